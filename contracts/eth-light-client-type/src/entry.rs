@@ -0,0 +1,234 @@
+use alloc::{vec, vec::Vec};
+
+use blake2b_ref::Blake2bBuilder;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::Entity as _,
+    high_level::{
+        load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_input, load_script,
+        load_script_hash, load_witness_args, QueryIter,
+    },
+};
+use eth_light_client_in_ckb_verification::types::{core, packed, prelude::*};
+
+use crate::error::Error;
+
+const BLAKE2B_PERSONALIZATION: &[u8] = b"ckb-default-hash";
+
+// The cells of the client in a source.
+struct ClientCells {
+    infos: Vec<core::ClientInfo>,
+    clients: Vec<core::Client>,
+}
+
+pub fn main() -> Result<(), Error> {
+    let script = load_script()?;
+    let args = script.args().raw_data();
+    let args = packed::ClientTypeArgsReader::from_slice(&args).map_err(|_| Error::InvalidArgs)?;
+    if !args.chain_spec().is_valid() {
+        return Err(Error::InvalidArgs);
+    }
+    let args: core::ClientTypeArgs = args.unpack();
+    if args.cells_count == 0 {
+        return Err(Error::InvalidArgs);
+    }
+
+    let inputs = ClientCells::load(Source::GroupInput)?;
+    let outputs = ClientCells::load(Source::GroupOutput)?;
+    match (inputs.is_empty(), outputs.is_empty()) {
+        (true, false) => create(&args, &outputs),
+        (false, false) if inputs.clients.is_empty() && outputs.clients.is_empty() => {
+            change_minimal_updates_count(&args, &inputs, &outputs)
+        }
+        (false, false) => update(&args, &inputs, &outputs),
+        (false, true) => destroy(&args, &inputs),
+        (true, true) => Err(Error::Other),
+    }
+}
+
+fn create(args: &core::ClientTypeArgs, outputs: &ClientCells) -> Result<(), Error> {
+    check_type_id(&args.type_id)?;
+    let info = outputs.check_all(args.cells_count)?;
+
+    // The client is bootstrapped from the trusted block root in the args, so the sync committee
+    // in the bootstrap is anchored.
+    let witness_args = load_witness_args(0, Source::GroupOutput)?;
+    let bootstrap = witness_args
+        .output_type()
+        .to_opt()
+        .ok_or(Error::InvalidWitness)?
+        .raw_data();
+    let bootstrap = packed::LightClientBootstrapReader::from_slice(&bootstrap)
+        .map_err(|_| Error::InvalidWitness)?;
+    let client =
+        core::Client::new_from_bootstrap(&args.chain_spec, args.trusted_block_root, bootstrap)
+            .map_err(|_| Error::Bootstrap)?;
+
+    // All client cells are the new client, and the one whose id is 0 is the latest.
+    if info.last_id != 0 {
+        return Err(Error::InvalidInfoCell);
+    }
+    let mut created = vec![false; usize::from(args.cells_count)];
+    for output_client in &outputs.clients {
+        let id = usize::from(output_client.id);
+        if id >= created.len() || created[id] {
+            return Err(Error::InvalidClientCell);
+        }
+        created[id] = true;
+        let mut expected = client.clone();
+        expected.id = output_client.id;
+        if expected.pack().as_slice() != output_client.pack().as_slice() {
+            return Err(Error::InvalidClientCell);
+        }
+    }
+
+    Ok(())
+}
+
+fn update(
+    args: &core::ClientTypeArgs,
+    inputs: &ClientCells,
+    outputs: &ClientCells,
+) -> Result<(), Error> {
+    let input_info = inputs.check_one()?;
+    let output_info = outputs.check_one()?;
+    let input_client = &inputs.clients[0];
+    let output_client = &outputs.clients[0];
+
+    // The oldest client cell is replaced by the new client.
+    input_info.verify_rotation(args.cells_count, input_client, output_info, output_client)?;
+
+    let prev_client = if args.cells_count == 1 {
+        input_client.clone()
+    } else {
+        // The latest client should be in the cell deps.
+        let clients = load_clients_in_cell_deps()?;
+        input_info
+            .select_latest_client(&clients)
+            .cloned()
+            .ok_or(Error::ItemMissing)?
+    };
+
+    // The witness is a proof update to append new headers, or a headers window proof to drop
+    // old headers, which keeps the count of the last slots in the args.
+    let witness_args = load_witness_args(0, Source::GroupInput)?;
+    let witness = witness_args
+        .input_type()
+        .to_opt()
+        .ok_or(Error::InvalidWitness)?
+        .raw_data();
+    let client_update =
+        packed::ClientUpdateReader::from_slice(&witness).map_err(|_| Error::InvalidWitness)?;
+    let client = match client_update.to_enum() {
+        packed::ClientUpdateUnionReader::ProofUpdate(proof_update) => prev_client
+            .try_apply_packed_proof_update_with_info(&args.chain_spec, input_info, proof_update)
+            .map_err(|_| Error::ProofUpdate)?,
+        packed::ClientUpdateUnionReader::HeadersWindowProof(proof) => prev_client
+            .try_slide_headers_window_with_info(args.headers_window_size, input_info, proof)
+            .map_err(|_| Error::HeadersWindow)?,
+    };
+    let (_, client) = input_info.rotate(args.cells_count, client)?;
+    if client.pack().as_slice() != output_client.pack().as_slice() {
+        return Err(Error::InvalidClientCell);
+    }
+
+    Ok(())
+}
+
+// Only the info cell is updated by the owner, to change the minimal updates count.
+fn change_minimal_updates_count(
+    args: &core::ClientTypeArgs,
+    inputs: &ClientCells,
+    outputs: &ClientCells,
+) -> Result<(), Error> {
+    if inputs.infos.len() != 1 || outputs.infos.len() != 1 {
+        return Err(Error::InvalidCellsCount);
+    }
+    let is_authorized = QueryIter::new(load_cell_lock_hash, Source::Input)
+        .any(|lock_hash| args.owner_lock_hash.as_bytes() == &lock_hash[..]);
+    if !is_authorized {
+        return Err(Error::Unauthorized);
+    }
+    inputs.infos[0].verify_minimal_updates_count_change(&args.chain_spec, &outputs.infos[0])?;
+    Ok(())
+}
+
+fn destroy(args: &core::ClientTypeArgs, inputs: &ClientCells) -> Result<(), Error> {
+    inputs.check_all(args.cells_count).map(|_| ())
+}
+
+impl ClientCells {
+    fn load(source: Source) -> Result<Self, Error> {
+        let mut infos = Vec::new();
+        let mut clients = Vec::new();
+        for data in QueryIter::new(load_cell_data, source) {
+            if let Ok(info) = packed::ClientInfoReader::from_slice(&data) {
+                infos.push(info.unpack());
+            } else if let Ok(client) = packed::ClientReader::from_slice(&data) {
+                clients.push(client.unpack());
+            } else {
+                return Err(Error::InvalidCellData);
+            }
+        }
+        Ok(Self { infos, clients })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.infos.is_empty() && self.clients.is_empty()
+    }
+
+    // Checks that there are the info cell and all client cells, returns the info.
+    fn check_all(&self, cells_count: u8) -> Result<&core::ClientInfo, Error> {
+        if self.infos.len() != 1 || self.clients.len() != usize::from(cells_count) {
+            return Err(Error::InvalidCellsCount);
+        }
+        Ok(&self.infos[0])
+    }
+
+    // Checks that there are the info cell and only one client cell, returns the info.
+    fn check_one(&self) -> Result<&core::ClientInfo, Error> {
+        if self.infos.len() != 1 || self.clients.len() != 1 {
+            return Err(Error::InvalidCellsCount);
+        }
+        Ok(&self.infos[0])
+    }
+}
+
+// Loads the client cells in the cell deps, which have the same type script.
+fn load_clients_in_cell_deps() -> Result<Vec<core::Client>, Error> {
+    let script_hash = load_script_hash()?;
+    let mut clients = Vec::new();
+    for (index, type_hash_opt) in QueryIter::new(load_cell_type_hash, Source::CellDep).enumerate() {
+        if type_hash_opt != Some(script_hash) {
+            continue;
+        }
+        let data = load_cell_data(index, Source::CellDep)?;
+        if let Ok(client) = packed::ClientReader::from_slice(&data) {
+            clients.push(client.unpack());
+        }
+    }
+    Ok(clients)
+}
+
+// Checks the type ID, which is the hash of the first input and the index of the first output
+// in the group.
+fn check_type_id(type_id: &core::Hash) -> Result<(), Error> {
+    let script_hash = load_script_hash()?;
+    let first_input = load_input(0, Source::Input)?;
+    let first_output_index = QueryIter::new(load_cell_type_hash, Source::Output)
+        .position(|type_hash_opt| type_hash_opt == Some(script_hash))
+        .ok_or(Error::InvalidTypeId)?;
+
+    let mut hasher = Blake2bBuilder::new(32)
+        .personal(BLAKE2B_PERSONALIZATION)
+        .build();
+    hasher.update(first_input.as_slice());
+    hasher.update(&(first_output_index as u64).to_le_bytes());
+    let mut expected = [0u8; 32];
+    hasher.finalize(&mut expected);
+
+    if type_id.as_bytes() != &expected[..] {
+        return Err(Error::InvalidTypeId);
+    }
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use ckb_std::error::SysError;
+use eth_light_client_in_ckb_verification::error::ClientCellsError;
+
+#[repr(i8)]
+pub enum Error {
+    IndexOutOfBound = 1,
+    ItemMissing,
+    LengthNotEnough,
+    Encoding,
+    InvalidArgs,
+    InvalidTypeId,
+    InvalidCellsCount,
+    InvalidCellData,
+    InvalidInfoCell,
+    InvalidClientCell,
+    InvalidWitness,
+    ProofUpdate,
+    Bootstrap,
+    HeadersWindow,
+    Other = 15,
+    Unauthorized,
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        match err {
+            SysError::IndexOutOfBound => Self::IndexOutOfBound,
+            SysError::ItemMissing => Self::ItemMissing,
+            SysError::LengthNotEnough(_) => Self::LengthNotEnough,
+            SysError::Encoding => Self::Encoding,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl From<ClientCellsError> for Error {
+    fn from(err: ClientCellsError) -> Self {
+        match err {
+            ClientCellsError::CellsCount => Self::InvalidArgs,
+            ClientCellsError::LastId
+            | ClientCellsError::NewLastId
+            | ClientCellsError::MinimalUpdatesCount => Self::InvalidInfoCell,
+            ClientCellsError::ReplacedClientId | ClientCellsError::NewClientId => {
+                Self::InvalidClientCell
+            }
+            ClientCellsError::Other => Self::Other,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! The type script of the client cells and the client info cell.
+//!
+//! The args of the script is a `ClientTypeArgs`, all cells which use the same script are a
+//! group of `cells_count` client cells and one client info cell. The preset in its chain spec
+//! should be valid, since the helpers divide slots by its values, and the forks should be
+//! scheduled in order, without any fork which the script doesn't know.
+//!
+//! - Create: the type ID is checked, and all client cells are the client which is created by
+//!   the bootstrap in the witness, whose header should be the trusted block root in the args.
+//! - Update: the oldest client cell is replaced by the client which is updated from the latest
+//!   client by the `ClientUpdate` in the witness, and `last_id` of the client info cell is bumped.
+//!   - A proof update should contain at least `minimal_updates_count` non-empty finalized
+//!     headers.
+//!   - A headers window proof drops the headers before the last `headers_window_size` slots in
+//!     the args, and at least `minimal_updates_count` slots should be dropped. The headers window
+//!     couldn't be slid if `headers_window_size` is zero.
+//! - Change the minimal updates count: only the client info cell is updated, and there should be
+//!   an input cell whose lock hash is `owner_lock_hash` in the args. The minimal updates count
+//!   could be raised or lowered, from one up to the slots in an epoch.
+//! - Destroy: all cells in the group are consumed.
+
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+mod entry;
+mod error;
+
+ckb_std::entry!(program_entry);
+ckb_std::default_alloc!(4 * 1024, 1258 * 1024, 64);
+
+pub fn program_entry() -> i8 {
+    match entry::main() {
+        Ok(_) => 0,
+        Err(err) => err as i8,
+    }
+}
@@ -0,0 +1,29 @@
+use std::{env, fs, path::PathBuf};
+
+use ckb_testtool::ckb_types::bytes::Bytes;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Loads the contracts which are built by `make build-contracts`.
+pub struct Loader(PathBuf);
+
+impl Default for Loader {
+    fn default() -> Self {
+        let mut base_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        base_path.push("../build/release");
+        Self(base_path)
+    }
+}
+
+impl Loader {
+    pub fn load_binary(&self, name: &str) -> Bytes {
+        let mut path = self.0.clone();
+        path.push(name);
+        fs::read(&path)
+            .unwrap_or_else(|err| panic!("failed to load {}: {err}", path.display()))
+            .into()
+    }
+}
@@ -0,0 +1,381 @@
+use std::{fs, iter, path::PathBuf};
+
+use blst::min_pk::{AggregatePublicKey, AggregateSignature, SecretKey};
+use eth2_hashing::hash32_concat;
+use eth2_types::BeaconBlockHeader;
+use merkle_proof::MerkleTree;
+use tree_hash::TreeHash as _;
+
+use eth_light_client_in_ckb_prover::ProofUpdateBuilder;
+use eth_light_client_in_ckb_verification::{
+    bls, consensus_specs as specs,
+    mmr::lib::util::MemStore,
+    types::{core, packed, prelude::*},
+};
+
+// There are 24 fields in `BeaconState` of Altair, and Bellatrix has more fields in the same depth.
+const BEACON_STATE_FIELDS_COUNT: usize = 24;
+const BEACON_STATE_DEPTH: usize = 5;
+
+const TEST_DATA_ROOT: &str = "../../tests/data";
+
+/// A sync committee of the mainnet with known secret keys, to sign attested headers.
+pub(crate) struct MockSyncCommittee {
+    secret_keys: Vec<SecretKey>,
+    sync_committee: core::SyncCommittee,
+    spec: core::ChainSpec,
+}
+
+impl MockSyncCommittee {
+    pub(crate) fn new(seed: u8) -> Self {
+        let spec = core::ChainSpec::MAINNET;
+        let secret_keys = (0..spec.preset.sync_committee_size)
+            .map(|index| {
+                let mut ikm = [seed; 32];
+                ikm[..8].copy_from_slice(&index.to_le_bytes());
+                SecretKey::key_gen(&ikm, &[]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let pubkeys = secret_keys
+            .iter()
+            .map(|secret_key| secret_key.sk_to_pk())
+            .collect::<Vec<_>>();
+        let aggregate_pubkey = {
+            let pubkeys_refs = pubkeys.iter().collect::<Vec<_>>();
+            AggregatePublicKey::aggregate(&pubkeys_refs, false)
+                .unwrap()
+                .to_public_key()
+        };
+        let sync_committee = core::SyncCommittee {
+            pubkeys: pubkeys.iter().map(|pubkey| pubkey.compress()).collect(),
+            aggregate_pubkey: aggregate_pubkey.compress(),
+        };
+        Self {
+            secret_keys,
+            sync_committee,
+            spec,
+        }
+    }
+
+    pub(crate) fn packed(&self) -> packed::SyncCommittee {
+        self.sync_committee.pack()
+    }
+
+    pub(crate) fn root(&self) -> core::Hash {
+        self.packed().as_reader().calc_root(&self.spec)
+    }
+
+    /// Creates a bootstrap from the header, with its state root replaced by a mocked state which
+    /// contains the sync committee.
+    pub(crate) fn bootstrap(&self, header: &core::Header) -> packed::LightClientBootstrap {
+        let mut header = header.clone();
+        let state = self.attested_state(&header);
+        header.state_root = state.hash();
+        let index = specs::altair::CURRENT_SYNC_COMMITTEE_INDEX - (1 << BEACON_STATE_DEPTH);
+        let (_, current_sync_committee_branch) =
+            state.generate_proof(index, BEACON_STATE_DEPTH).unwrap();
+        core::LightClientBootstrap {
+            header,
+            current_sync_committee: self.sync_committee.clone(),
+            current_sync_committee_branch,
+        }
+        .pack()
+    }
+
+    // A mocked beacon state, only the finalized checkpoint and the sync committees are meaningful.
+    fn attested_state(&self, finalized_header: &core::Header) -> MerkleTree {
+        let leaf_index = |index: usize| index - (1 << BEACON_STATE_DEPTH);
+        let finalized_checkpoint_index = leaf_index(specs::altair::FINALIZED_ROOT_INDEX >> 1);
+        let current_sync_committee_index = leaf_index(specs::altair::CURRENT_SYNC_COMMITTEE_INDEX);
+        let next_sync_committee_index = leaf_index(specs::altair::NEXT_SYNC_COMMITTEE_INDEX);
+        let sync_committee_root = self.root();
+        let leaves = (0..BEACON_STATE_FIELDS_COUNT)
+            .map(|index| {
+                if index == finalized_checkpoint_index {
+                    let epoch_root = core::Hash::zero();
+                    let root = finalized_header.tree_hash_root();
+                    core::Hash::from(hash32_concat(epoch_root.as_bytes(), root.as_bytes()))
+                } else if index == current_sync_committee_index
+                    || index == next_sync_committee_index
+                {
+                    sync_committee_root
+                } else {
+                    core::Hash::repeat_byte(index as u8)
+                }
+            })
+            .collect::<Vec<_>>();
+        MerkleTree::create(&leaves, BEACON_STATE_DEPTH)
+    }
+
+    /// Creates a finality update for the finalized header, which is signed by all members of the
+    /// sync committee, and the proof of the next sync committee in its attested state.
+    ///
+    /// The next sync committee is the same as the current sync committee.
+    pub(crate) fn attest(
+        &self,
+        finalized_header: packed::Header,
+    ) -> (packed::FinalityUpdate, core::SszProof) {
+        let finalized_header: core::Header = finalized_header.unpack();
+        let attested_state = self.attested_state(&finalized_header);
+        let generate_proof = |generalized_index: usize| {
+            let index = generalized_index - (1 << BEACON_STATE_DEPTH);
+            let (_, proof) = attested_state
+                .generate_proof(index, BEACON_STATE_DEPTH)
+                .unwrap();
+            proof
+        };
+        // The epoch of the finalized checkpoint is the sibling of its root.
+        let finality_branch = iter::once(core::Hash::zero())
+            .chain(generate_proof(specs::altair::FINALIZED_ROOT_INDEX >> 1))
+            .collect::<Vec<_>>();
+        let next_sync_committee_branch = generate_proof(specs::altair::NEXT_SYNC_COMMITTEE_INDEX);
+
+        let attested_header = core::Header {
+            slot: finalized_header.slot + self.spec.preset.slots_per_epoch * 2,
+            proposer_index: 1,
+            parent_root: core::Hash::repeat_byte(1),
+            state_root: attested_state.hash(),
+            body_root: core::Hash::repeat_byte(3),
+        };
+        let signature_slot = attested_header.slot + 1;
+        let signing_root = {
+            let epoch = self.spec.compute_epoch_at_slot(signature_slot - 1);
+            let domain = specs::helpers::compute_domain(
+                specs::altair::DOMAIN_SYNC_COMMITTEE,
+                self.spec.compute_fork_version(epoch),
+                self.spec.genesis_validators_root,
+            );
+            specs::helpers::compute_signing_root(attested_header.tree_hash_root(), domain)
+        };
+        let signatures = self
+            .secret_keys
+            .iter()
+            .map(|secret_key| secret_key.sign(signing_root.as_bytes(), bls::DST, &[]))
+            .collect::<Vec<_>>();
+        let sync_committee_signature = {
+            let signatures_refs = signatures.iter().collect::<Vec<_>>();
+            AggregateSignature::aggregate(&signatures_refs, false)
+                .unwrap()
+                .to_signature()
+                .compress()
+        };
+        let finality_update = core::FinalityUpdate {
+            attested_header,
+            finalized_header,
+            finality_branch,
+            sync_aggregate: core::SyncAggregate {
+                sync_committee_bits: vec![0xff; self.secret_keys.len() / 8],
+                sync_committee_signature,
+            },
+            signature_slot,
+        }
+        .pack();
+        (finality_update, next_sync_committee_branch)
+    }
+}
+
+fn load_beacon_block_header_from_json_or_create_default(file: PathBuf) -> BeaconBlockHeader {
+    let slot: u64 = {
+        let file_stem = file.file_stem().unwrap().to_str().unwrap();
+        let slot_str = file_stem.strip_prefix("block-header-slot-").unwrap();
+        slot_str.parse().unwrap()
+    };
+    let json_str = fs::read_to_string(file).unwrap();
+    let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    if json_value.get("code").is_some() {
+        BeaconBlockHeader {
+            slot: slot.into(),
+            proposer_index: 0,
+            parent_root: Default::default(),
+            state_root: Default::default(),
+            body_root: Default::default(),
+        }
+    } else {
+        serde_json::from_value(json_value["data"]["header"]["message"].clone()).unwrap()
+    }
+}
+
+/// Loads the headers of a test case in the mainnet, in the order of their slots.
+pub(crate) fn load_headers(case_id: usize) -> Vec<BeaconBlockHeader> {
+    let case_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join(TEST_DATA_ROOT)
+        .join(format!("mainnet/case-{case_id}/beacon"));
+    let mut files = fs::read_dir(case_dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.starts_with("block-header-slot-") && name.ends_with(".json"))
+                .unwrap_or(false)
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+        .into_iter()
+        .map(load_beacon_block_header_from_json_or_create_default)
+        .collect()
+}
+
+/// A client which is bootstrapped from the first header, and the proof updates for the rest
+/// headers.
+pub(crate) struct MockClient {
+    /// The root of the header in the bootstrap.
+    pub(crate) trusted_block_root: core::Hash,
+    /// The bytes of the bootstrap.
+    pub(crate) bootstrap: Vec<u8>,
+    /// The client which is created from the bootstrap.
+    pub(crate) client: core::Client,
+    /// The bytes of each client update with a proof update, and the client after it is applied.
+    pub(crate) proof_updates: Vec<(Vec<u8>, core::Client)>,
+    /// The bytes of the client update with a headers window proof for the last client, and the
+    /// client after it is applied.
+    pub(crate) headers_window_update: (Vec<u8>, core::Client),
+}
+
+/// Bootstraps a client from the first header, then builds proof updates for the rest headers,
+/// each proof update contains a chunk of headers.
+/// At last, the headers window of the last client is slid to `window_size`.
+///
+/// The state root of the first header is replaced in the bootstrap, so the parent roots of the
+/// rest headers are replaced to keep them continuous.
+pub(crate) fn build_client(
+    headers: &[BeaconBlockHeader],
+    chunk_size: usize,
+    window_size: u64,
+) -> MockClient {
+    let spec = core::ChainSpec::MAINNET;
+    let sync_committee = MockSyncCommittee::new(0);
+    let bootstrap = {
+        let header: core::Header = packed::Header::from_ssz_header(&headers[0]).unpack();
+        sync_committee.bootstrap(&header)
+    };
+    let bootstrap_header = {
+        let header: core::Header = bootstrap.header().unpack();
+        header.calc_cache()
+    };
+    let mut builder = ProofUpdateBuilder::with_bootstrap(
+        spec,
+        bootstrap_header.root,
+        bootstrap.clone(),
+        MemStore::default(),
+    )
+    .unwrap_or_else(|_| panic!("failed to create client from bootstrap"));
+    let client = builder.client().clone();
+
+    let mut tip_valid_header_root = bootstrap_header.root;
+    let proof_updates = headers[1..]
+        .chunks(chunk_size)
+        .map(|chunk| {
+            for header in chunk {
+                let mut header = header.clone();
+                let is_empty = {
+                    let header: core::Header = packed::Header::from_ssz_header(&header).unpack();
+                    header.is_empty()
+                };
+                if !is_empty {
+                    header.parent_root = tip_valid_header_root;
+                    tip_valid_header_root = header.tree_hash_root();
+                }
+                builder.push_header(&header);
+            }
+            let tip_valid_header = builder.tip_valid_header().cloned().unwrap();
+            let (tip_update, next_sync_committee_branch) = sync_committee.attest(tip_valid_header);
+            builder.set_tip_update(tip_update);
+            builder.set_next_sync_committee(sync_committee.root(), next_sync_committee_branch);
+            let proof_update = builder
+                .build()
+                .unwrap_or_else(|_| panic!("failed to build proof update"));
+            let update = core::ClientUpdate::ProofUpdate(proof_update.unpack());
+            let client = builder.client().clone();
+            (update.pack().as_slice().to_owned(), client)
+        })
+        .collect();
+
+    let headers_window_update = {
+        let proof = builder
+            .slide_headers_window(window_size, MemStore::default())
+            .unwrap_or_else(|_| panic!("failed to slide headers window"));
+        let update = core::ClientUpdate::HeadersWindowProof(proof.unpack());
+        (
+            update.pack().as_slice().to_owned(),
+            builder.client().clone(),
+        )
+    };
+
+    MockClient {
+        trusted_block_root: bootstrap_header.root,
+        bootstrap: bootstrap.as_slice().to_owned(),
+        client,
+        proof_updates,
+        headers_window_update,
+    }
+}
+
+/// The bytes of the bootstrap, with its sync committee replaced by another one.
+pub(crate) fn bootstrap_with_another_sync_committee(bootstrap: &[u8]) -> Vec<u8> {
+    let mut bootstrap: core::LightClientBootstrap =
+        packed::LightClientBootstrapReader::from_slice(bootstrap)
+            .unwrap()
+            .unpack();
+    bootstrap.current_sync_committee = MockSyncCommittee::new(1).sync_committee;
+    bootstrap.pack().as_slice().to_owned()
+}
+
+/// The bytes of the client update with a headers window proof, with the first digest in the
+/// proof dropped.
+pub(crate) fn headers_window_update_without_first_digest(update: &[u8]) -> Vec<u8> {
+    let update: core::ClientUpdate = packed::ClientUpdateReader::from_slice(update)
+        .unwrap()
+        .unpack();
+    let core::ClientUpdate::HeadersWindowProof(mut proof) = update else {
+        panic!("not a headers window proof");
+    };
+    proof.digests.remove(0);
+    core::ClientUpdate::HeadersWindowProof(proof)
+        .pack()
+        .as_slice()
+        .to_owned()
+}
+
+/// The args for the type script of client cells on the mainnet.
+pub(crate) fn client_type_args(
+    type_id: [u8; 32],
+    cells_count: u8,
+    trusted_block_root: core::Hash,
+    headers_window_size: u64,
+    owner_lock_hash: core::Hash,
+) -> core::ClientTypeArgs {
+    core::ClientTypeArgs {
+        type_id: core::Hash::from(type_id),
+        cells_count,
+        chain_spec: core::ChainSpec::MAINNET,
+        trusted_block_root,
+        headers_window_size,
+        owner_lock_hash,
+    }
+}
+
+/// The bytes of the args for the type script of client cells.
+pub(crate) fn client_type_args_bytes(args: &core::ClientTypeArgs) -> Vec<u8> {
+    args.pack().as_slice().to_owned()
+}
+
+/// The bytes of a client info cell.
+pub(crate) fn client_info(last_id: u8, minimal_updates_count: u8) -> Vec<u8> {
+    core::ClientInfo {
+        last_id,
+        minimal_updates_count,
+    }
+    .pack()
+    .as_slice()
+    .to_owned()
+}
+
+/// The bytes of a client cell, with the id replaced.
+pub(crate) fn client_with_id(client: &core::Client, id: u8) -> Vec<u8> {
+    let mut client = client.clone();
+    client.id = id;
+    client.pack().as_slice().to_owned()
+}
@@ -0,0 +1,651 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_hash::new_blake2b,
+    ckb_types::{
+        bytes::Bytes,
+        core::{TransactionBuilder, TransactionView},
+        packed::{self, CellDep, CellInput, CellOutput, OutPoint, Script, WitnessArgs},
+        prelude::*,
+    },
+    context::Context,
+};
+use eth_light_client_in_ckb_verification::types::core;
+
+use crate::{mock, Loader};
+
+const MAX_CYCLES: u64 = 10_000_000_000;
+const CONTRACT: &str = "eth-light-client-type";
+
+const CELLS_COUNT: u8 = 3;
+const MINIMAL_UPDATES_COUNT: u8 = 1;
+// The first header is used to bootstrap the client, and each proof update contains 21 headers.
+const CHUNK_SIZE: usize = 21;
+const WINDOW_SIZE: u64 = 32;
+
+// The error codes of the contract.
+const ERROR_INVALID_ARGS: i8 = 5;
+const ERROR_INVALID_TYPE_ID: i8 = 6;
+const ERROR_INVALID_CELLS_COUNT: i8 = 7;
+const ERROR_INVALID_INFO_CELL: i8 = 9;
+const ERROR_INVALID_CLIENT_CELL: i8 = 10;
+const ERROR_INVALID_WITNESS: i8 = 11;
+const ERROR_PROOF_UPDATE: i8 = 12;
+const ERROR_BOOTSTRAP: i8 = 13;
+const ERROR_HEADERS_WINDOW: i8 = 14;
+const ERROR_UNAUTHORIZED: i8 = 16;
+
+struct Env {
+    context: Context,
+    contract_out_point: OutPoint,
+    lock_script: Script,
+}
+
+impl Env {
+    fn new() -> Self {
+        let mut context = Context::default();
+        let contract_out_point = context.deploy_cell(Loader::default().load_binary(CONTRACT));
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let lock_script = context
+            .build_script(&always_success_out_point, Default::default())
+            .unwrap();
+        Self {
+            context,
+            contract_out_point,
+            lock_script,
+        }
+    }
+
+    // The lock script of all cells is the owner of the client cells.
+    fn owner_lock_hash(&self) -> core::Hash {
+        core::Hash::from_slice(self.lock_script.calc_script_hash().as_slice())
+    }
+
+    fn client_type_args(
+        &self,
+        type_id: [u8; 32],
+        cells_count: u8,
+        trusted_block_root: core::Hash,
+    ) -> core::ClientTypeArgs {
+        mock::client_type_args(
+            type_id,
+            cells_count,
+            trusted_block_root,
+            WINDOW_SIZE,
+            self.owner_lock_hash(),
+        )
+    }
+
+    fn type_script(
+        &mut self,
+        type_id: [u8; 32],
+        cells_count: u8,
+        trusted_block_root: core::Hash,
+    ) -> Script {
+        let args = self.client_type_args(type_id, cells_count, trusted_block_root);
+        self.type_script_with_args(&args)
+    }
+
+    fn type_script_with_args(&mut self, args: &core::ClientTypeArgs) -> Script {
+        let args = mock::client_type_args_bytes(args);
+        self.context
+            .build_script(&self.contract_out_point, args.into())
+            .unwrap()
+    }
+
+    fn cell_output(&self, type_script_opt: Option<&Script>) -> CellOutput {
+        CellOutput::new_builder()
+            .capacity(100_000_000_000u64.pack())
+            .lock(self.lock_script.clone())
+            .type_(type_script_opt.cloned().pack())
+            .build()
+    }
+
+    fn create_input(&mut self, type_script_opt: Option<&Script>, data: Vec<u8>) -> CellInput {
+        let output = self.cell_output(type_script_opt);
+        let out_point = self.context.create_cell(output, data.into());
+        CellInput::new_builder().previous_output(out_point).build()
+    }
+
+    fn create_cell_dep(&mut self, type_script: &Script, data: Vec<u8>) -> CellDep {
+        let output = self.cell_output(Some(type_script));
+        let out_point = self.context.create_cell(output, data.into());
+        CellDep::new_builder().out_point(out_point).build()
+    }
+
+    fn verify(&mut self, tx: TransactionView) -> Result<u64, i8> {
+        let tx = self.context.complete_tx(tx);
+        self.context.verify_tx(&tx, MAX_CYCLES).map_err(|err| {
+            // The error is formatted as "... error code <CODE> ...".
+            let message = err.to_string();
+            message
+                .split("error code ")
+                .nth(1)
+                .and_then(|rest| rest.split(|c: char| !c.is_ascii_digit() && c != '-').next())
+                .and_then(|code| code.parse().ok())
+                .unwrap_or_else(|| panic!("unknown error: {message}"))
+        })
+    }
+}
+
+fn data(bytes: Vec<u8>) -> packed::Bytes {
+    Bytes::from(bytes).pack()
+}
+
+fn calc_type_id(first_input: &CellInput, first_output_index: u64) -> [u8; 32] {
+    let mut hasher = new_blake2b();
+    hasher.update(first_input.as_slice());
+    hasher.update(&first_output_index.to_le_bytes());
+    let mut type_id = [0u8; 32];
+    hasher.finalize(&mut type_id);
+    type_id
+}
+
+fn build_client() -> mock::MockClient {
+    let headers = mock::load_headers(1);
+    mock::build_client(&headers, CHUNK_SIZE, WINDOW_SIZE)
+}
+
+// Builds a transaction to create the client cells, the type ID in the type script is the one
+// which is calculated from the input.
+fn build_create_tx(
+    env: &mut Env,
+    cells_count: u8,
+    trusted_block_root: core::Hash,
+    bootstrap: &[u8],
+    client: &core::Client,
+) -> TransactionView {
+    let input = env.create_input(None, Vec::new());
+    let type_id = calc_type_id(&input, 0);
+    let type_script = env.type_script(type_id, cells_count, trusted_block_root);
+    let output = env.cell_output(Some(&type_script));
+    let mut builder = TransactionBuilder::default().input(input);
+    for id in 0..cells_count {
+        builder = builder
+            .output(output.clone())
+            .output_data(data(mock::client_with_id(client, id)));
+    }
+    let witness = WitnessArgs::new_builder()
+        .output_type(Some(Bytes::from(bootstrap.to_owned())).pack())
+        .build();
+    builder
+        .output(output)
+        .output_data(data(mock::client_info(0, MINIMAL_UPDATES_COUNT)))
+        .witness(witness.as_bytes().pack())
+        .build()
+}
+
+#[test]
+fn create_client_cells() {
+    let mock_client = build_client();
+
+    for cells_count in [1, CELLS_COUNT] {
+        let mut env = Env::new();
+        let tx = build_create_tx(
+            &mut env,
+            cells_count,
+            mock_client.trusted_block_root,
+            &mock_client.bootstrap,
+            &mock_client.client,
+        );
+        let result = env.verify(tx);
+        assert!(result.is_ok(), "failed to create: {result:?}");
+    }
+}
+
+#[test]
+fn create_client_cells_with_untrusted_bootstrap() {
+    let mock_client = build_client();
+
+    // The header in the bootstrap is not the trusted checkpoint.
+    let mut env = Env::new();
+    let tx = build_create_tx(
+        &mut env,
+        CELLS_COUNT,
+        core::Hash::repeat_byte(0xff),
+        &mock_client.bootstrap,
+        &mock_client.client,
+    );
+    assert_eq!(env.verify(tx), Err(ERROR_BOOTSTRAP));
+
+    // The sync committee in the bootstrap is not the one in the state of the header.
+    let bootstrap = mock::bootstrap_with_another_sync_committee(&mock_client.bootstrap);
+    let mut env = Env::new();
+    let tx = build_create_tx(
+        &mut env,
+        CELLS_COUNT,
+        mock_client.trusted_block_root,
+        &bootstrap,
+        &mock_client.client,
+    );
+    assert_eq!(env.verify(tx), Err(ERROR_BOOTSTRAP));
+}
+
+#[test]
+fn create_client_cells_with_invalid_type_id() {
+    let mock_client = build_client();
+
+    let mut env = Env::new();
+    let tx = build_create_tx(
+        &mut env,
+        CELLS_COUNT,
+        mock_client.trusted_block_root,
+        &mock_client.bootstrap,
+        &mock_client.client,
+    );
+    let type_script = env.type_script([0u8; 32], CELLS_COUNT, mock_client.trusted_block_root);
+    let outputs = tx
+        .outputs()
+        .into_iter()
+        .map(|output| {
+            output
+                .as_builder()
+                .type_(Some(type_script.clone()).pack())
+                .build()
+        })
+        .collect::<Vec<_>>();
+    let tx = tx.as_advanced_builder().set_outputs(outputs).build();
+    assert_eq!(env.verify(tx), Err(ERROR_INVALID_TYPE_ID));
+}
+
+#[test]
+fn create_client_cells_with_invalid_chain_spec() {
+    let mock_client = build_client();
+
+    let mut env = Env::new();
+    let tx = build_create_tx(
+        &mut env,
+        CELLS_COUNT,
+        mock_client.trusted_block_root,
+        &mock_client.bootstrap,
+        &mock_client.client,
+    );
+    let type_id = calc_type_id(&tx.inputs().get(0).unwrap(), 0);
+    // The slots would be divided by 0.
+    let zero_slots_per_epoch = core::ChainSpec {
+        preset: core::Preset {
+            slots_per_epoch: 0,
+            ..core::Preset::MAINNET
+        },
+        ..core::ChainSpec::MAINNET
+    };
+    // Deneb is scheduled before Capella.
+    let mut unordered_forks = core::ChainSpec::MAINNET;
+    unordered_forks.forks.swap(2, 3);
+    for chain_spec in [zero_slots_per_epoch, unordered_forks] {
+        let args = core::ClientTypeArgs {
+            chain_spec,
+            ..env.client_type_args(type_id, CELLS_COUNT, mock_client.trusted_block_root)
+        };
+        let type_script = env.type_script_with_args(&args);
+        let outputs = tx
+            .outputs()
+            .into_iter()
+            .map(|output| {
+                output
+                    .as_builder()
+                    .type_(Some(type_script.clone()).pack())
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        let tx = tx.as_advanced_builder().set_outputs(outputs).build();
+        assert_eq!(env.verify(tx), Err(ERROR_INVALID_ARGS));
+    }
+}
+
+#[test]
+fn create_client_cells_with_invalid_cells_count() {
+    let mock_client = build_client();
+
+    let mut env = Env::new();
+    let tx = build_create_tx(
+        &mut env,
+        CELLS_COUNT,
+        mock_client.trusted_block_root,
+        &mock_client.bootstrap,
+        &mock_client.client,
+    );
+    let outputs = tx.outputs().into_iter().skip(1).collect::<Vec<_>>();
+    let outputs_data = tx.outputs_data().into_iter().skip(1).collect::<Vec<_>>();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs(outputs)
+        .set_outputs_data(outputs_data)
+        .build();
+    assert_eq!(env.verify(tx), Err(ERROR_INVALID_CELLS_COUNT));
+}
+
+#[test]
+fn create_client_cells_with_invalid_client() {
+    let mock_client = build_client();
+
+    let mut env = Env::new();
+    let tx = build_create_tx(
+        &mut env,
+        CELLS_COUNT,
+        mock_client.trusted_block_root,
+        &mock_client.bootstrap,
+        &mock_client.client,
+    );
+    // The same id is used twice.
+    let mut outputs_data = tx.outputs_data().into_iter().collect::<Vec<_>>();
+    outputs_data[1] = outputs_data[0].clone();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data)
+        .build();
+    assert_eq!(env.verify(tx), Err(ERROR_INVALID_CLIENT_CELL));
+}
+
+// The client cells after creation, the latest client cell is in the cell deps, and the info cell
+// and the oldest client cell are the inputs.
+struct UpdateCase {
+    env: Env,
+    type_script: Script,
+    cell_dep_opt: Option<CellDep>,
+    inputs: Vec<CellInput>,
+    next_id: u8,
+}
+
+fn prepare_update(cells_count: u8, minimal_updates_count: u8, client: &core::Client) -> UpdateCase {
+    let env = Env::new();
+    let args = env.client_type_args([1u8; 32], cells_count, core::Hash::zero());
+    prepare_update_with_args(env, &args, minimal_updates_count, client)
+}
+
+fn prepare_update_with_args(
+    mut env: Env,
+    args: &core::ClientTypeArgs,
+    minimal_updates_count: u8,
+    client: &core::Client,
+) -> UpdateCase {
+    let type_script = env.type_script_with_args(args);
+    let cells_count = args.cells_count;
+    let last_id = 0;
+    let next_id = (last_id + 1) % cells_count;
+    let cell_dep_opt = if cells_count > 1 {
+        Some(env.create_cell_dep(&type_script, mock::client_with_id(client, last_id)))
+    } else {
+        None
+    };
+    let inputs = vec![
+        env.create_input(
+            Some(&type_script),
+            mock::client_info(last_id, minimal_updates_count),
+        ),
+        env.create_input(Some(&type_script), mock::client_with_id(client, next_id)),
+    ];
+    UpdateCase {
+        env,
+        type_script,
+        cell_dep_opt,
+        inputs,
+        next_id,
+    }
+}
+
+// The client update in the witness has a proof update or a headers window proof.
+fn build_update_tx(
+    case: &UpdateCase,
+    update: &[u8],
+    client_data: Vec<u8>,
+    info_data: Vec<u8>,
+) -> TransactionView {
+    let output = case.env.cell_output(Some(&case.type_script));
+    let witness = WitnessArgs::new_builder()
+        .input_type(Some(Bytes::from(update.to_owned())).pack())
+        .build();
+    let builder = TransactionBuilder::default()
+        .inputs(case.inputs.clone())
+        .output(output.clone())
+        .output_data(data(info_data))
+        .output(output)
+        .output_data(data(client_data))
+        .witness(witness.as_bytes().pack());
+    if let Some(ref cell_dep) = case.cell_dep_opt {
+        builder.cell_dep(cell_dep.clone()).build()
+    } else {
+        builder.build()
+    }
+}
+
+#[test]
+fn update_client_cells() {
+    let mock_client = build_client();
+    let (_, prev_client) = &mock_client.proof_updates[0];
+    let (proof_update, client) = &mock_client.proof_updates[1];
+
+    for cells_count in [1, CELLS_COUNT] {
+        let mut case = prepare_update(cells_count, MINIMAL_UPDATES_COUNT, prev_client);
+        let tx = build_update_tx(
+            &case,
+            proof_update,
+            mock::client_with_id(client, case.next_id),
+            mock::client_info(case.next_id, MINIMAL_UPDATES_COUNT),
+        );
+        let result = case.env.verify(tx);
+        assert!(result.is_ok(), "failed to update: {result:?}");
+    }
+}
+
+#[test]
+fn update_client_cells_with_invalid_client() {
+    let mock_client = build_client();
+    let (prev_proof_update, prev_client) = &mock_client.proof_updates[0];
+    let (proof_update, client) = &mock_client.proof_updates[1];
+
+    let mut case = prepare_update(CELLS_COUNT, MINIMAL_UPDATES_COUNT, prev_client);
+
+    // The id of the new client is not the oldest one.
+    let tx = build_update_tx(
+        &case,
+        proof_update,
+        mock::client_with_id(client, case.next_id + 1),
+        mock::client_info(case.next_id, MINIMAL_UPDATES_COUNT),
+    );
+    assert_eq!(case.env.verify(tx), Err(ERROR_INVALID_CLIENT_CELL));
+
+    // The new client is not the one which is updated by the proof update.
+    let tx = build_update_tx(
+        &case,
+        proof_update,
+        mock::client_with_id(prev_client, case.next_id),
+        mock::client_info(case.next_id, MINIMAL_UPDATES_COUNT),
+    );
+    assert_eq!(case.env.verify(tx), Err(ERROR_INVALID_CLIENT_CELL));
+
+    // The proof update is already applied.
+    let tx = build_update_tx(
+        &case,
+        prev_proof_update,
+        mock::client_with_id(client, case.next_id),
+        mock::client_info(case.next_id, MINIMAL_UPDATES_COUNT),
+    );
+    assert_eq!(case.env.verify(tx), Err(ERROR_PROOF_UPDATE));
+}
+
+#[test]
+fn update_client_cells_with_too_few_updates() {
+    let mock_client = build_client();
+    let (_, prev_client) = &mock_client.proof_updates[0];
+    let (proof_update, client) = &mock_client.proof_updates[1];
+
+    // There are only `CHUNK_SIZE` updates in the proof update.
+    let minimal_updates_count = CHUNK_SIZE as u8 + 1;
+    let mut case = prepare_update(CELLS_COUNT, minimal_updates_count, prev_client);
+    let tx = build_update_tx(
+        &case,
+        proof_update,
+        mock::client_with_id(client, case.next_id),
+        mock::client_info(case.next_id, minimal_updates_count),
+    );
+    assert_eq!(case.env.verify(tx), Err(ERROR_PROOF_UPDATE));
+}
+
+#[test]
+fn slide_headers_window_of_client_cells() {
+    let mock_client = build_client();
+    let (_, prev_client) = mock_client.proof_updates.last().unwrap();
+    let (window_update, client) = &mock_client.headers_window_update;
+    assert_eq!(client.maximal_slot + 1 - client.minimal_slot, WINDOW_SIZE);
+
+    for cells_count in [1, CELLS_COUNT] {
+        let mut case = prepare_update(cells_count, MINIMAL_UPDATES_COUNT, prev_client);
+        let tx = build_update_tx(
+            &case,
+            window_update,
+            mock::client_with_id(client, case.next_id),
+            mock::client_info(case.next_id, MINIMAL_UPDATES_COUNT),
+        );
+        let result = case.env.verify(tx);
+        assert!(result.is_ok(), "failed to slide headers window: {result:?}");
+    }
+}
+
+#[test]
+fn slide_headers_window_of_client_cells_with_invalid_witness() {
+    let mock_client = build_client();
+    let (_, prev_client) = mock_client.proof_updates.last().unwrap();
+    let (window_update, client) = &mock_client.headers_window_update;
+
+    let mut case = prepare_update(CELLS_COUNT, MINIMAL_UPDATES_COUNT, prev_client);
+    let client_data = mock::client_with_id(client, case.next_id);
+    let info_data = mock::client_info(case.next_id, MINIMAL_UPDATES_COUNT);
+
+    // The count of the digests in the proof is not the window size in the args.
+    let invalid_window_update = mock::headers_window_update_without_first_digest(window_update);
+    let tx = build_update_tx(
+        &case,
+        &invalid_window_update,
+        client_data.clone(),
+        info_data.clone(),
+    );
+    assert_eq!(case.env.verify(tx), Err(ERROR_HEADERS_WINDOW));
+
+    // The headers window proof is not wrapped in a client update.
+    let tx = build_update_tx(&case, &window_update[4..], client_data, info_data);
+    assert_eq!(case.env.verify(tx), Err(ERROR_INVALID_WITNESS));
+}
+
+#[test]
+fn slide_headers_window_of_client_cells_with_invalid_args() {
+    let mock_client = build_client();
+    let (_, prev_client) = mock_client.proof_updates.last().unwrap();
+    let (window_update, client) = &mock_client.headers_window_update;
+
+    // The window size in the args is not the one in the proof, or the headers window couldn't be
+    // slid.
+    for headers_window_size in [WINDOW_SIZE - 1, 0] {
+        let env = Env::new();
+        let args = core::ClientTypeArgs {
+            headers_window_size,
+            ..env.client_type_args([1u8; 32], CELLS_COUNT, core::Hash::zero())
+        };
+        let mut case = prepare_update_with_args(env, &args, MINIMAL_UPDATES_COUNT, prev_client);
+        let tx = build_update_tx(
+            &case,
+            window_update,
+            mock::client_with_id(client, case.next_id),
+            mock::client_info(case.next_id, MINIMAL_UPDATES_COUNT),
+        );
+        assert_eq!(case.env.verify(tx), Err(ERROR_HEADERS_WINDOW));
+    }
+}
+
+#[test]
+fn slide_headers_window_of_client_cells_with_too_few_dropped_slots() {
+    let mock_client = build_client();
+    let (_, prev_client) = mock_client.proof_updates.last().unwrap();
+    let (window_update, client) = &mock_client.headers_window_update;
+
+    let dropped_count = prev_client.maximal_slot + 1 - prev_client.minimal_slot - WINDOW_SIZE;
+    let minimal_updates_count = dropped_count as u8 + 1;
+    let mut case = prepare_update(CELLS_COUNT, minimal_updates_count, prev_client);
+    let tx = build_update_tx(
+        &case,
+        window_update,
+        mock::client_with_id(client, case.next_id),
+        mock::client_info(case.next_id, minimal_updates_count),
+    );
+    assert_eq!(case.env.verify(tx), Err(ERROR_HEADERS_WINDOW));
+}
+
+#[test]
+fn change_minimal_updates_count() {
+    let mut env = Env::new();
+    let type_script = env.type_script([1u8; 32], CELLS_COUNT, core::Hash::zero());
+    let input = env.create_input(Some(&type_script), mock::client_info(1, 4));
+    let output = env.cell_output(Some(&type_script));
+    let build_tx = |last_id: u8, minimal_updates_count: u8| {
+        TransactionBuilder::default()
+            .input(input.clone())
+            .output(output.clone())
+            .output_data(data(mock::client_info(last_id, minimal_updates_count)))
+            .build()
+    };
+
+    for minimal_updates_count in [8, 2] {
+        let result = env.verify(build_tx(1, minimal_updates_count));
+        assert!(result.is_ok(), "failed to change: {result:?}");
+    }
+
+    // Zero, or more than the slots in an epoch.
+    for minimal_updates_count in [0, 33] {
+        let tx = build_tx(1, minimal_updates_count);
+        assert_eq!(env.verify(tx), Err(ERROR_INVALID_INFO_CELL));
+    }
+
+    // No client cells are replaced, so the last id could not be changed.
+    assert_eq!(env.verify(build_tx(2, 8)), Err(ERROR_INVALID_INFO_CELL));
+}
+
+#[test]
+fn change_minimal_updates_count_without_owner() {
+    let mut env = Env::new();
+    // The owner is not the lock of any input.
+    let args = core::ClientTypeArgs {
+        owner_lock_hash: core::Hash::repeat_byte(0xff),
+        ..env.client_type_args([1u8; 32], CELLS_COUNT, core::Hash::zero())
+    };
+    let type_script = env.type_script_with_args(&args);
+    let input = env.create_input(Some(&type_script), mock::client_info(1, 4));
+    let output = env.cell_output(Some(&type_script));
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .output(output)
+        .output_data(data(mock::client_info(1, 8)))
+        .build();
+    assert_eq!(env.verify(tx), Err(ERROR_UNAUTHORIZED));
+}
+
+#[test]
+fn destroy_client_cells() {
+    let mock_client = build_client();
+    let client = &mock_client.client;
+
+    let mut env = Env::new();
+    let type_script = env.type_script([1u8; 32], CELLS_COUNT, core::Hash::zero());
+    let mut inputs = (0..CELLS_COUNT)
+        .map(|id| env.create_input(Some(&type_script), mock::client_with_id(client, id)))
+        .collect::<Vec<_>>();
+    inputs.push(env.create_input(
+        Some(&type_script),
+        mock::client_info(0, MINIMAL_UPDATES_COUNT),
+    ));
+    let output = env.cell_output(None);
+
+    let tx = TransactionBuilder::default()
+        .inputs(inputs.clone())
+        .output(output.clone())
+        .output_data(Bytes::new().pack())
+        .build();
+    let result = env.verify(tx);
+    assert!(result.is_ok(), "failed to destroy: {result:?}");
+
+    // All client cells should be destroyed together.
+    let tx = TransactionBuilder::default()
+        .inputs(inputs.into_iter().skip(1))
+        .output(output)
+        .output_data(Bytes::new().pack())
+        .build();
+    assert_eq!(env.verify(tx), Err(ERROR_INVALID_CELLS_COUNT));
+}
@@ -1,8 +1,13 @@
-use eth2_types::{BeaconBlock, EthSpec, ExecPayload as _, Slot, Transaction};
+use eth2_types::{
+    BeaconBlock, BeaconBlockHeader, EthSpec, ExecPayload as _, Slot, Transaction, Withdrawal,
+};
 use merkle_proof::MerkleTree;
 use tree_hash::{Hash256, TreeHash};
 
-use eth_light_client_in_ckb_verification::{consensus_specs as specs, ssz};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{self as specs, helpers::Fork},
+    ssz,
+};
 
 #[derive(Clone)]
 pub struct CachedBeaconBlock<T>
@@ -42,8 +47,18 @@ where
 
     // Capella
     withdrawals_root: Option<Hash256>,
+    withdrawals_depth: usize,
+    withdrawal_hashes: Vec<Hash256>,
     bls_to_execution_changes_root: Option<Hash256>,
 
+    // Deneb
+    blob_gas_used_root: Option<Hash256>,
+    excess_blob_gas_root: Option<Hash256>,
+    blob_kzg_commitments_root: Option<Hash256>,
+
+    // Electra
+    execution_requests_root: Option<Hash256>,
+
     original: BeaconBlock<T>,
 }
 
@@ -66,11 +81,37 @@ where
             MerkleTree::create(&transaction_hashes, transactions_depth).hash();
 
         let withdrawals_root = payload.withdrawals_root().ok();
+        let withdrawal_hashes = payload
+            .execution_payload_ref()
+            .withdrawals()
+            .map(|withdrawals| {
+                withdrawals
+                    .iter()
+                    .map(|withdrawal| withdrawal.tree_hash_root())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let withdrawals_depth = ssz::ceil_depth(T::max_withdrawals_per_payload()) as usize;
         let bls_to_execution_changes_root = body
             .bls_to_execution_changes()
             .ok()
             .map(TreeHash::tree_hash_root);
 
+        let blob_gas_used_root = payload
+            .blob_gas_used()
+            .ok()
+            .map(|blob_gas_used| blob_gas_used.tree_hash_root());
+        let excess_blob_gas_root = payload
+            .excess_blob_gas()
+            .ok()
+            .map(|excess_blob_gas| excess_blob_gas.tree_hash_root());
+        let blob_kzg_commitments_root = body
+            .blob_kzg_commitments()
+            .ok()
+            .map(TreeHash::tree_hash_root);
+
+        let execution_requests_root = body.execution_requests().ok().map(TreeHash::tree_hash_root);
+
         Self {
             body_root: block.body_root(),
             randao_reveal_root: body.randao_reveal().tree_hash_root(),
@@ -104,8 +145,16 @@ where
             transaction_hashes,
 
             withdrawals_root,
+            withdrawals_depth,
+            withdrawal_hashes,
             bls_to_execution_changes_root,
 
+            blob_gas_used_root,
+            excess_blob_gas_root,
+            blob_kzg_commitments_root,
+
+            execution_requests_root,
+
             original: block,
         }
     }
@@ -123,6 +172,16 @@ where
         self.original.slot()
     }
 
+    pub fn header(&self) -> BeaconBlockHeader {
+        BeaconBlockHeader {
+            slot: self.original.slot(),
+            proposer_index: self.original.proposer_index(),
+            parent_root: self.original.parent_root(),
+            state_root: self.original.state_root(),
+            body_root: self.body_root,
+        }
+    }
+
     pub fn number(&self) -> u64 {
         self.original
             .body()
@@ -155,6 +214,22 @@ where
             .cloned()
     }
 
+    /// The count of withdrawals in the execution payload, always be zero before Capella.
+    pub fn withdrawals_count(&self) -> usize {
+        self.withdrawal_hashes.len()
+    }
+
+    pub fn withdrawal(&self, index: usize) -> Option<Withdrawal> {
+        self.original
+            .body()
+            .execution_payload()
+            .unwrap()
+            .execution_payload_ref()
+            .withdrawals()
+            .ok()
+            .and_then(|withdrawals| withdrawals.get(index).cloned())
+    }
+
     pub fn body_root(&self) -> Hash256 {
         self.body_root
     }
@@ -163,6 +238,11 @@ where
         self.execution_payload_root
     }
 
+    /// The state root of the execution payload.
+    pub fn execution_state_root(&self) -> Hash256 {
+        self.state_root
+    }
+
     pub fn transactions_root(&self) -> Hash256 {
         self.transactions_root
     }
@@ -187,81 +267,131 @@ where
 
     pub fn generate_transaction_proof_for_execution_payload(&self, index: usize) -> Vec<Hash256> {
         let mut proof = self.generate_transaction_proof_for_transactions(index);
-        let mut leaves = vec![
-            self.parent_hash_root,
-            self.fee_recipient_root,
-            self.state_root,
-            self.receipts_root,
-            self.logs_bloom_root,
-            self.prev_randao_root,
-            self.block_number_root,
-            self.gas_limit_root,
-            self.gas_used_root,
-            self.timestamp_root,
-            self.extra_data_root,
-            self.base_fee_per_gas_root,
-            self.block_hash_root,
-            self.transactions_root,
-        ];
-        let (depth, field_index) = if self.slot()
-            < specs::helpers::compute_start_slot_at_epoch(specs::capella::FORK_EPOCH)
-        {
-            use specs::bellatrix::containers;
-            assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
-            let depth = containers::EXECUTION_PAYLOAD_DEPTH as usize;
-            let field_index = containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX;
-            (depth, field_index)
-        } else {
-            use specs::capella::containers;
-            leaves.push(self.withdrawals_root.unwrap());
-            assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
-            let depth = containers::EXECUTION_PAYLOAD_DEPTH as usize;
-            let field_index = containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX;
-            (depth, field_index)
+        let field_index = match self.fork() {
+            Fork::Electra => specs::electra::containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX,
+            Fork::Deneb => specs::deneb::containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX,
+            Fork::Capella => specs::capella::containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX,
+            _ => specs::bellatrix::containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX,
         };
-        let tree = MerkleTree::create(&leaves, depth);
-        let (_, fields_proof) = tree.generate_proof(field_index, depth).unwrap();
+        let fields_proof = self.generate_field_proof_for_execution_payload(field_index);
         proof.extend(fields_proof);
         proof
     }
 
     pub fn generate_transaction_proof_for_block_body(&self, index: usize) -> Vec<Hash256> {
         let mut proof = self.generate_transaction_proof_for_execution_payload(index);
-        let mut leaves = vec![
-            self.randao_reveal_root,
-            self.eth1_data_root,
-            self.graffiti_root,
-            self.proposer_slashings_root,
-            self.attester_slashings_root,
-            self.attestations_root,
-            self.deposits_root,
-            self.voluntary_exits_root,
-            self.sync_aggregate_root,
-            self.execution_payload_root,
-        ];
-        let (depth, field_index) = if self.slot()
-            < specs::helpers::compute_start_slot_at_epoch(specs::capella::FORK_EPOCH)
-        {
-            use specs::bellatrix::containers;
-            assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
-            let depth = containers::BLOCK_BODY_DEPTH as usize;
-            let field_index = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-            (depth, field_index)
-        } else {
-            use specs::capella::containers;
-            leaves.push(self.bls_to_execution_changes_root.unwrap());
-            assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
-            let depth = containers::BLOCK_BODY_DEPTH as usize;
-            let field_index = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-            (depth, field_index)
+        let fields_proof = self.generate_execution_payload_proof_for_block_body();
+        proof.extend(fields_proof);
+        proof
+    }
+
+    pub fn generate_receipts_root_proof_for_execution_payload(&self) -> Vec<Hash256> {
+        let field_index = match self.fork() {
+            Fork::Electra => specs::electra::containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+            Fork::Deneb => specs::deneb::containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+            Fork::Capella => specs::capella::containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+            _ => specs::bellatrix::containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+        };
+        self.generate_field_proof_for_execution_payload(field_index)
+    }
+
+    pub fn generate_receipts_root_proof_for_block_body(&self) -> Vec<Hash256> {
+        let mut proof = self.generate_receipts_root_proof_for_execution_payload();
+        let fields_proof = self.generate_execution_payload_proof_for_block_body();
+        proof.extend(fields_proof);
+        proof
+    }
+
+    pub fn generate_state_root_proof_for_execution_payload(&self) -> Vec<Hash256> {
+        let field_index = match self.fork() {
+            Fork::Electra => specs::electra::containers::STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+            Fork::Deneb => specs::deneb::containers::STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+            Fork::Capella => specs::capella::containers::STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+            _ => specs::bellatrix::containers::STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
         };
+        self.generate_field_proof_for_execution_payload(field_index)
+    }
+
+    pub fn generate_state_root_proof_for_block_body(&self) -> Vec<Hash256> {
+        let mut proof = self.generate_state_root_proof_for_execution_payload();
+        let fields_proof = self.generate_execution_payload_proof_for_block_body();
+        proof.extend(fields_proof);
+        proof
+    }
+
+    pub fn generate_withdrawal_proof_for_withdrawals(&self, index: usize) -> Vec<Hash256> {
+        let depth = self.withdrawals_depth;
+        let tree = MerkleTree::create(&self.withdrawal_hashes, depth);
+        let (_, mut proof) = tree.generate_proof(index, depth).unwrap();
+        let item = ssz::length_hash(self.withdrawal_hashes.len());
+        proof.push(item);
+        proof
+    }
+
+    /// Generates the proof for a withdrawal in the block body.
+    ///
+    /// The block should be a block since Capella, and the index should be less than
+    /// [`withdrawals_count()`](Self::withdrawals_count).
+    pub fn generate_withdrawal_proof(&self, index: usize) -> Vec<Hash256> {
+        let mut proof = self.generate_withdrawal_proof_for_withdrawals(index);
+        let field_index = specs::capella::containers::WITHDRAWALS_IN_EXECUTION_PAYLOAD_INDEX;
+        let fields_proof = self.generate_field_proof_for_block_body(field_index);
+        proof.extend(fields_proof);
+        proof
+    }
+
+    /// The hash tree root of a field in the execution payload.
+    pub fn execution_payload_field_root(&self, field_index: usize) -> Option<Hash256> {
+        let (leaves, _) = self.execution_payload_fields();
+        leaves.get(field_index).cloned()
+    }
+
+    pub fn generate_field_proof_for_execution_payload(&self, field_index: usize) -> Vec<Hash256> {
+        let (leaves, depth) = self.execution_payload_fields();
         let tree = MerkleTree::create(&leaves, depth);
-        let (_, fields_proof) = tree.generate_proof(field_index, depth).unwrap();
+        let (_, proof) = tree.generate_proof(field_index, depth).unwrap();
+        proof
+    }
+
+    pub fn generate_field_proof_for_block_body(&self, field_index: usize) -> Vec<Hash256> {
+        let mut proof = self.generate_field_proof_for_execution_payload(field_index);
+        let fields_proof = self.generate_execution_payload_proof_for_block_body();
         proof.extend(fields_proof);
         proof
     }
 
-    pub fn generate_receipts_root_proof_for_execution_payload(&self) -> Vec<Hash256> {
+    pub fn generate_execution_payload_proof_for_block_body(&self) -> Vec<Hash256> {
+        let (leaves, depth) = self.block_body_fields();
+        let field_index = match self.fork() {
+            Fork::Electra => specs::electra::containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX,
+            Fork::Deneb => specs::deneb::containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX,
+            Fork::Capella => specs::capella::containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX,
+            _ => specs::bellatrix::containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX,
+        };
+        let tree = MerkleTree::create(&leaves, depth);
+        let (_, proof) = tree.generate_proof(field_index, depth).unwrap();
+        proof
+    }
+
+    /// The fork which the block belongs to.
+    ///
+    /// It's detected by the fields in the block body, so it doesn't depend on the fork epochs of
+    /// the network.
+    /// A block of Fulu is detected as Electra, since the block body isn't changed in Fulu.
+    pub fn fork(&self) -> Fork {
+        if self.execution_requests_root.is_some() {
+            Fork::Electra
+        } else if self.blob_kzg_commitments_root.is_some() {
+            Fork::Deneb
+        } else if self.withdrawals_root.is_some() {
+            Fork::Capella
+        } else {
+            Fork::Bellatrix
+        }
+    }
+
+    // Returns the roots of all fields in the execution payload, and the depth of its tree.
+    fn execution_payload_fields(&self) -> (Vec<Hash256>, usize) {
         let mut leaves = vec![
             self.parent_hash_root,
             self.fee_recipient_root,
@@ -278,30 +408,40 @@ where
             self.block_hash_root,
             self.transactions_root,
         ];
-        let (depth, field_index) = if self.slot()
-            < specs::helpers::compute_start_slot_at_epoch(specs::capella::FORK_EPOCH)
-        {
-            use specs::bellatrix::containers;
-            assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
-            let depth = containers::EXECUTION_PAYLOAD_DEPTH as usize;
-            let field_index = containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
-            (depth, field_index)
-        } else {
-            use specs::capella::containers;
-            leaves.push(self.withdrawals_root.unwrap());
-            assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
-            let depth = containers::EXECUTION_PAYLOAD_DEPTH as usize;
-            let field_index = containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
-            (depth, field_index)
+        let depth = match self.fork() {
+            Fork::Electra => {
+                use specs::electra::containers;
+                leaves.push(self.withdrawals_root.unwrap());
+                leaves.push(self.blob_gas_used_root.unwrap());
+                leaves.push(self.excess_blob_gas_root.unwrap());
+                assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
+                containers::EXECUTION_PAYLOAD_DEPTH as usize
+            }
+            Fork::Deneb => {
+                use specs::deneb::containers;
+                leaves.push(self.withdrawals_root.unwrap());
+                leaves.push(self.blob_gas_used_root.unwrap());
+                leaves.push(self.excess_blob_gas_root.unwrap());
+                assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
+                containers::EXECUTION_PAYLOAD_DEPTH as usize
+            }
+            Fork::Capella => {
+                use specs::capella::containers;
+                leaves.push(self.withdrawals_root.unwrap());
+                assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
+                containers::EXECUTION_PAYLOAD_DEPTH as usize
+            }
+            _ => {
+                use specs::bellatrix::containers;
+                assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
+                containers::EXECUTION_PAYLOAD_DEPTH as usize
+            }
         };
-
-        let tree = MerkleTree::create(&leaves, depth);
-        let (_, proof) = tree.generate_proof(field_index, depth).unwrap();
-        proof
+        (leaves, depth)
     }
 
-    pub fn generate_receipts_root_proof_for_block_body(&self) -> Vec<Hash256> {
-        let mut proof = self.generate_receipts_root_proof_for_execution_payload();
+    // Returns the roots of all fields in the block body, and the depth of its tree.
+    fn block_body_fields(&self) -> (Vec<Hash256>, usize) {
         let mut leaves = vec![
             self.randao_reveal_root,
             self.eth1_data_root,
@@ -314,25 +454,34 @@ where
             self.sync_aggregate_root,
             self.execution_payload_root,
         ];
-        let (depth, field_index) = if self.slot()
-            < specs::helpers::compute_start_slot_at_epoch(specs::capella::FORK_EPOCH)
-        {
-            use specs::bellatrix::containers;
-            assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
-            let depth = containers::BLOCK_BODY_DEPTH as usize;
-            let field_index = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-            (depth, field_index)
-        } else {
-            use specs::capella::containers;
-            leaves.push(self.bls_to_execution_changes_root.unwrap());
-            assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
-            let depth = containers::BLOCK_BODY_DEPTH as usize;
-            let field_index = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-            (depth, field_index)
+        let depth = match self.fork() {
+            Fork::Electra => {
+                use specs::electra::containers;
+                leaves.push(self.bls_to_execution_changes_root.unwrap());
+                leaves.push(self.blob_kzg_commitments_root.unwrap());
+                leaves.push(self.execution_requests_root.unwrap());
+                assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
+                containers::BLOCK_BODY_DEPTH as usize
+            }
+            Fork::Deneb => {
+                use specs::deneb::containers;
+                leaves.push(self.bls_to_execution_changes_root.unwrap());
+                leaves.push(self.blob_kzg_commitments_root.unwrap());
+                assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
+                containers::BLOCK_BODY_DEPTH as usize
+            }
+            Fork::Capella => {
+                use specs::capella::containers;
+                leaves.push(self.bls_to_execution_changes_root.unwrap());
+                assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
+                containers::BLOCK_BODY_DEPTH as usize
+            }
+            _ => {
+                use specs::bellatrix::containers;
+                assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
+                containers::BLOCK_BODY_DEPTH as usize
+            }
         };
-        let tree = MerkleTree::create(&leaves, depth);
-        let (_, fields_proof) = tree.generate_proof(field_index, depth).unwrap();
-        proof.extend(fields_proof);
-        proof
+        (leaves, depth)
     }
 }
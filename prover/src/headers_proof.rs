@@ -0,0 +1,56 @@
+use eth2_types::BeaconBlockHeader;
+
+use eth_light_client_in_ckb_verification::{
+    error::HeaderVerificationError,
+    mmr::{
+        self,
+        lib::{leaf_index_to_pos, MMRStore},
+    },
+    types::{core, packed, prelude::*},
+};
+
+/// Builds one MMR proof for several headers, and verifies it with the client before returning.
+///
+/// The headers are sorted by slots, and the duplicated headers are removed.
+/// The headers MMR should be the same as the one in the client.
+pub fn build_headers_proof<S>(
+    client: &core::Client,
+    headers_mmr: &mmr::ClientRootMMR<S>,
+    headers: &[BeaconBlockHeader],
+) -> Result<(packed::HeaderVec, packed::MmrProof), HeaderVerificationError>
+where
+    S: MMRStore<packed::HeaderDigest>,
+{
+    let mut headers = headers
+        .iter()
+        .map(|header| packed::Header::from_ssz_header(header).unpack())
+        .collect::<Vec<core::Header>>();
+    headers.sort_unstable_by_key(|header| header.slot);
+    headers.dedup_by_key(|header| header.slot);
+
+    let positions = headers
+        .iter()
+        .map(|header| {
+            if header.slot < client.minimal_slot || header.slot > client.maximal_slot {
+                Err(HeaderVerificationError::Unsynchronized)
+            } else {
+                Ok(leaf_index_to_pos(header.slot - client.minimal_slot))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if positions.is_empty() {
+        return Err(HeaderVerificationError::EmptyHeaders);
+    }
+    let proof_items = headers_mmr
+        .gen_proof(positions)
+        .map_err(|_| HeaderVerificationError::HeaderMmrProof)?
+        .proof_items()
+        .to_owned();
+
+    let packed_headers = headers.pack();
+    let packed_proof = packed::MmrProof::new_builder().set(proof_items).build();
+
+    client.verify_packed_headers(packed_headers.as_reader(), packed_proof.as_reader())?;
+
+    Ok((packed_headers, packed_proof))
+}
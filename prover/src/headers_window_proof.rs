@@ -0,0 +1,64 @@
+use eth_light_client_in_ckb_verification::{
+    error::HeadersWindowError,
+    mmr::{
+        self,
+        lib::{leaf_index_to_mmr_size, leaf_index_to_pos, MMRStore},
+    },
+    types::{core, packed, prelude::*},
+};
+
+/// Builds the proof to drop the headers before the last `window_size` slots of the client, and
+/// verifies it with the client before returning.
+///
+/// The store should contain the headers MMR of the client.
+/// Returns the proof and the new client, whose headers MMR only contains the digests in the
+/// proof.
+pub fn build_headers_window_proof<S>(
+    client: &core::Client,
+    store: &S,
+    window_size: u64,
+) -> Result<(packed::HeadersWindowProof, core::Client), HeadersWindowError>
+where
+    for<'a> &'a S: MMRStore<packed::HeaderDigest>,
+{
+    let headers_count = client.maximal_slot - client.minimal_slot + 1;
+    if window_size == 0 || window_size >= headers_count {
+        return Err(HeadersWindowError::WindowSize);
+    }
+    let first_index = headers_count - window_size;
+    let positions = (first_index..headers_count)
+        .map(leaf_index_to_pos)
+        .collect::<Vec<_>>();
+
+    let digests = positions
+        .iter()
+        .map(|position| {
+            store
+                .get_elem(*position)
+                .ok()
+                .flatten()
+                .map(|digest| digest.unpack())
+                .ok_or(HeadersWindowError::Other)
+        })
+        .collect::<Result<Vec<core::HeaderDigest>, _>>()?;
+
+    let headers_mmr = mmr::ClientRootMMR::new(leaf_index_to_mmr_size(headers_count - 1), store);
+    let digests_mmr_proof = headers_mmr
+        .gen_proof(positions)
+        .map_err(|_| HeadersWindowError::HeadersMmrProof)?
+        .proof_items()
+        .iter()
+        .map(|item| item.unpack())
+        .collect();
+
+    let proof = core::HeadersWindowProof {
+        digests,
+        digests_mmr_proof,
+    };
+
+    let packed_proof = proof.pack();
+
+    let new_client = client.try_slide_headers_window(window_size, packed_proof.as_reader())?;
+
+    Ok((packed_proof, new_client))
+}
@@ -0,0 +1,71 @@
+use eth2_types::BeaconBlockHeader;
+use merkle_proof::MerkleTree;
+use tree_hash::Hash256;
+
+use eth_light_client_in_ckb_verification::{
+    error::HistoricalBlockVerificationError,
+    mmr::{self, lib::MMRStore},
+    types::{core, packed, prelude::*},
+};
+
+use crate::transaction_proof::generate_header_mmr_proof;
+
+/// Builds the proof for a historical header with an anchor header in the client, and verifies it
+/// with the client before returning.
+///
+/// The block roots should be the `block_roots` in the state of the anchor header, if the
+/// historical header is in the last `SLOTS_PER_HISTORICAL_ROOT` slots before the anchor header;
+/// otherwise, they should be the block roots which are summarized into the `block_summary_root`
+/// of the historical summary for the historical header.
+/// The SSZ proof proves the root of the block roots in the state root of the anchor header.
+/// The headers MMR should be the same as the one in the client.
+pub fn build_historical_block_proof<S>(
+    spec: &core::ChainSpec,
+    client: &core::Client,
+    headers_mmr: &mmr::ClientRootMMR<S>,
+    anchor_header: &BeaconBlockHeader,
+    header: &BeaconBlockHeader,
+    block_roots: &[Hash256],
+    block_roots_ssz_proof: &[Hash256],
+) -> Result<packed::HistoricalBlockProof, HistoricalBlockVerificationError>
+where
+    S: MMRStore<packed::HeaderDigest>,
+{
+    let anchor_slot: u64 = anchor_header.slot.into();
+    if anchor_slot < client.minimal_slot || anchor_slot > client.maximal_slot {
+        return Err(HistoricalBlockVerificationError::Unsynchronized);
+    }
+    let header_slot: u64 = header.slot.into();
+    if header_slot >= anchor_slot {
+        return Err(HistoricalBlockVerificationError::AnchorSlot);
+    }
+    let slots_per_historical_root = spec.preset.slots_per_historical_root;
+    if block_roots.len() as u64 != slots_per_historical_root {
+        return Err(HistoricalBlockVerificationError::Other);
+    }
+
+    let anchor_header_mmr_proof = generate_header_mmr_proof(client, headers_mmr, anchor_slot)
+        .map_err(|_| HistoricalBlockVerificationError::HeaderMmrProof)?;
+
+    let header_ssz_proof = {
+        let depth = spec.preset.block_roots_depth() as usize;
+        let index = (header_slot % slots_per_historical_root) as usize;
+        let tree = MerkleTree::create(block_roots, depth);
+        let (_, mut proof) = tree.generate_proof(index, depth).unwrap();
+        proof.extend_from_slice(block_roots_ssz_proof);
+        proof
+    };
+
+    let proof = core::HistoricalBlockProof {
+        anchor_header: packed::Header::from_ssz_header(anchor_header).unpack(),
+        header: packed::Header::from_ssz_header(header).unpack(),
+        anchor_header_mmr_proof,
+        header_ssz_proof,
+    };
+
+    let packed_proof = proof.pack();
+
+    client.verify_packed_historical_block_proof(spec, packed_proof.as_reader())?;
+
+    Ok(packed_proof)
+}
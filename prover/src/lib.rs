@@ -1,5 +1,25 @@
 mod cached_block;
+mod headers_proof;
+mod headers_window_proof;
+mod historical_block_proof;
+mod mmr_store;
+mod multiproof;
+mod payload_field_proof;
+mod proof_update;
 mod receipts;
+mod state_proof;
+mod transaction_proof;
+mod withdrawal_proof;
 
 pub use cached_block::CachedBeaconBlock;
+pub use headers_proof::build_headers_proof;
+pub use headers_window_proof::build_headers_window_proof;
+pub use historical_block_proof::build_historical_block_proof;
+pub use mmr_store::FileStore;
+pub use multiproof::build_multiproof;
+pub use payload_field_proof::build_execution_payload_field_proof;
+pub use proof_update::ProofUpdateBuilder;
 pub use receipts::{encode_receipt, Receipts};
+pub use state_proof::build_state_proof;
+pub use transaction_proof::{build_block_transactions_proof, build_transaction_proof};
+pub use withdrawal_proof::build_withdrawal_proof;
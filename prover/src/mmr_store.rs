@@ -0,0 +1,167 @@
+use std::{
+    cell::{Cell, RefCell},
+    fs::{self, File, OpenOptions},
+    io::{self, Read as _, Seek as _, SeekFrom, Write as _},
+    path::Path,
+};
+
+use eth_light_client_in_ckb_verification::{
+    mmr::{
+        self,
+        lib::{
+            leaf_index_to_mmr_size, leaf_index_to_pos, Error as MMRError, MMRStore,
+            Result as MMRResult,
+        },
+    },
+    types::{packed, prelude::*},
+};
+
+const DIGEST_SIZE: u64 = packed::HeaderDigest::TOTAL_SIZE as u64;
+const MMR_SIZE_SIZE: u64 = 8;
+
+const DATA_FILENAME: &str = "headers_mmr.data";
+const INDEX_FILENAME: &str = "headers_mmr.index";
+
+/// A file-backed store for the headers MMR.
+///
+/// All MMR nodes are appended into a data file as fixed-size digests, so the position of a node
+/// is its offset divided by the size of a digest.
+/// After each append is synced into the disk, the new MMR size is appended into an index file.
+///
+/// When the store is reopened, the MMR size is the last one in the index file, and the tails of
+/// both files, which are left by a crash, are truncated.
+pub struct FileStore {
+    data: RefCell<File>,
+    index: RefCell<File>,
+    mmr_size: Cell<u64>,
+}
+
+impl FileStore {
+    /// Opens the store in the directory, or creates it if it doesn't exist.
+    pub fn open<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let open_file = |filename| {
+            OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(dir.join(filename))
+        };
+        let data = open_file(DATA_FILENAME)?;
+        let mut index = open_file(INDEX_FILENAME)?;
+
+        let sizes_count = index.metadata()?.len() / MMR_SIZE_SIZE;
+        let mmr_size = if sizes_count == 0 {
+            0
+        } else {
+            let mut buf = [0u8; MMR_SIZE_SIZE as usize];
+            index.seek(SeekFrom::Start((sizes_count - 1) * MMR_SIZE_SIZE))?;
+            index.read_exact(&mut buf)?;
+            u64::from_le_bytes(buf)
+        };
+        if data.metadata()?.len() < mmr_size * DIGEST_SIZE {
+            let msg = format!("data of headers MMR is less than the indexed size {mmr_size}");
+            return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+        }
+        index.set_len(sizes_count * MMR_SIZE_SIZE)?;
+        index.sync_all()?;
+        data.set_len(mmr_size * DIGEST_SIZE)?;
+        data.sync_all()?;
+
+        let store = Self {
+            data: RefCell::new(data),
+            index: RefCell::new(index),
+            mmr_size: Cell::new(mmr_size),
+        };
+        Ok(store)
+    }
+
+    pub fn mmr_size(&self) -> u64 {
+        self.mmr_size.get()
+    }
+
+    /// The count of headers in the MMR.
+    pub fn leaves_count(&self) -> u64 {
+        let mmr_size = self.mmr_size();
+        let (mut low, mut high) = (0, mmr_size);
+        while low < high {
+            let mid = high - (high - low) / 2;
+            if leaf_index_to_mmr_size(mid - 1) <= mmr_size {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low
+    }
+
+    pub fn mmr(&self) -> mmr::ClientRootMMR<&Self> {
+        mmr::ClientRootMMR::new(self.mmr_size(), self)
+    }
+
+    pub fn get_root(&self) -> MMRResult<packed::HeaderDigest> {
+        self.mmr().get_root()
+    }
+
+    /// Generates the MMR proof for headers in the store.
+    ///
+    /// The leaf index of a header is the difference between its slot and the minimal slot of the
+    /// client.
+    pub fn gen_proof(&self, leaf_indexes: &[u64]) -> MMRResult<mmr::MMRProof> {
+        let positions = leaf_indexes
+            .iter()
+            .map(|index| leaf_index_to_pos(*index))
+            .collect();
+        self.mmr().gen_proof(positions)
+    }
+}
+
+impl MMRStore<packed::HeaderDigest> for &FileStore {
+    fn get_elem(&self, pos: u64) -> MMRResult<Option<packed::HeaderDigest>> {
+        if pos >= self.mmr_size() {
+            return Ok(None);
+        }
+        let mut buf = [0u8; DIGEST_SIZE as usize];
+        {
+            let mut data = self.data.borrow_mut();
+            data.seek(SeekFrom::Start(pos * DIGEST_SIZE))
+                .and_then(|_| data.read_exact(&mut buf))
+                .map_err(|err| MMRError::StoreError(err.to_string()))?;
+        }
+        let digest = packed::HeaderDigest::new_unchecked(buf.to_vec().into());
+        Ok(Some(digest))
+    }
+
+    fn append(&mut self, pos: u64, elems: Vec<packed::HeaderDigest>) -> MMRResult<()> {
+        let mmr_size = self.mmr_size();
+        if pos != mmr_size {
+            let msg = format!("append elements at position {pos} but MMR size is {mmr_size}");
+            return Err(MMRError::StoreError(msg));
+        }
+        let data = elems
+            .iter()
+            .flat_map(|elem| elem.as_slice().to_owned())
+            .collect::<Vec<_>>();
+        let new_mmr_size = mmr_size + elems.len() as u64;
+        {
+            let mut data_file = self.data.borrow_mut();
+            data_file
+                .seek(SeekFrom::Start(pos * DIGEST_SIZE))
+                .and_then(|_| data_file.write_all(&data))
+                .and_then(|_| data_file.sync_data())
+                .map_err(|err| MMRError::StoreError(err.to_string()))?;
+        }
+        {
+            let mut index_file = self.index.borrow_mut();
+            index_file
+                .seek(SeekFrom::End(0))
+                .and_then(|_| index_file.write_all(&new_mmr_size.to_le_bytes()))
+                .and_then(|_| index_file.sync_data())
+                .map_err(|err| MMRError::StoreError(err.to_string()))?;
+        }
+        self.mmr_size.set(new_mmr_size);
+        Ok(())
+    }
+}
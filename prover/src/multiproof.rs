@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use tree_hash::Hash256;
+
+use eth_light_client_in_ckb_verification::ssz;
+
+/// Builds a multiproof for several leaves in the same tree, from their single-branch proofs.
+///
+/// Each item is the generalized index of a leaf, and its branch from the bottom to the top, which
+/// could be verified by `ssz::verify_merkle_proof`. The sibling hashes shared by the branches are
+/// deduplicated, and the ones which could be calculated from the leaves are dropped.
+///
+/// Returns the helpers in the order of `ssz::get_helper_indices`, which could be verified by
+/// `ssz::verify_multiproof`; or `None` if the length of a branch is not matched with its index.
+pub fn build_multiproof(branches: &[(usize, &[Hash256])]) -> Option<Vec<Hash256>> {
+    let mut nodes = HashMap::new();
+    for (index, branch) in branches {
+        let mut node_index = *index;
+        for item in branch.iter() {
+            if node_index < 2 {
+                return None;
+            }
+            nodes.insert(node_index ^ 1, *item);
+            node_index /= 2;
+        }
+        if node_index != 1 {
+            return None;
+        }
+    }
+    let indices = branches.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+    ssz::get_helper_indices(&indices)
+        .into_iter()
+        .map(|index| nodes.get(&index).copied())
+        .collect()
+}
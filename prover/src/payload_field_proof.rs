@@ -0,0 +1,53 @@
+use eth2_types::EthSpec;
+
+use eth_light_client_in_ckb_verification::{
+    error::FieldVerificationError,
+    mmr::{self, lib::MMRStore},
+    types::{core, packed, prelude::*},
+};
+
+use crate::{transaction_proof::generate_header_mmr_proof, CachedBeaconBlock};
+
+/// Builds the proof for a field of the execution payload in the block, and verifies it with the
+/// client before returning.
+///
+/// The field index is the index in `ExecutionPayload`, such as
+/// `consensus_specs::capella::containers::BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX`.
+/// The headers MMR should be the same as the one in the client.
+pub fn build_execution_payload_field_proof<T, S>(
+    spec: &core::ChainSpec,
+    client: &core::Client,
+    headers_mmr: &mmr::ClientRootMMR<S>,
+    block: &CachedBeaconBlock<T>,
+    field_index: usize,
+) -> Result<packed::ExecutionPayloadFieldProof, FieldVerificationError>
+where
+    T: EthSpec,
+    S: MMRStore<packed::HeaderDigest>,
+{
+    let slot: u64 = block.slot().into();
+    if slot < client.minimal_slot || slot > client.maximal_slot {
+        return Err(FieldVerificationError::Unsynchronized);
+    }
+    let field_root = block
+        .execution_payload_field_root(field_index)
+        .ok_or(FieldVerificationError::FieldIndex)?;
+
+    let header: core::Header = packed::Header::from_ssz_header(&block.header()).unpack();
+    let header_mmr_proof = generate_header_mmr_proof(client, headers_mmr, slot)
+        .map_err(|_| FieldVerificationError::HeaderMmrProof)?;
+
+    let proof = core::ExecutionPayloadFieldProof {
+        header,
+        field_index: field_index as u8,
+        field_root,
+        header_mmr_proof,
+        field_ssz_proof: block.generate_field_proof_for_block_body(field_index),
+    };
+
+    let packed_proof = proof.pack();
+
+    client.verify_packed_execution_payload_field_proof(spec, packed_proof.as_reader())?;
+
+    Ok(packed_proof)
+}
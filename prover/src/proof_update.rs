@@ -0,0 +1,241 @@
+use eth2_types::BeaconBlockHeader;
+use tree_hash::Hash256;
+
+use eth_light_client_in_ckb_verification::{
+    error::{BootstrapError, HeadersWindowError, ProofUpdateError},
+    mmr::{
+        self,
+        lib::{leaf_index_to_mmr_size, util::MemStore, MMRStore},
+    },
+    types::{core, packed, prelude::*},
+};
+
+use crate::build_headers_window_proof;
+
+/// Builds packed proof updates from continuous beacon block headers, and keeps the headers MMR
+/// of the client.
+///
+/// The store should contain the headers MMR of the client.
+/// After each proof update is built and verified, the new headers are committed into the store.
+pub struct ProofUpdateBuilder<S = MemStore<packed::HeaderDigest>> {
+    spec: core::ChainSpec,
+    store: S,
+    client: core::Client,
+    headers: Vec<packed::Header>,
+    tip_update_opt: Option<packed::FinalityUpdate>,
+    sync_committee: packed::SyncCommittee,
+    next_sync_committee_opt: Option<(Hash256, Vec<Hash256>)>,
+}
+
+impl<S> ProofUpdateBuilder<S>
+where
+    for<'a> &'a S: MMRStore<packed::HeaderDigest>,
+{
+    /// Creates a builder for a new client from a bootstrap of a trusted block root, the store
+    /// should be empty.
+    ///
+    /// The current sync committee in the bootstrap is set to sign the following proof updates.
+    pub fn with_bootstrap(
+        spec: core::ChainSpec,
+        trusted_block_root: Hash256,
+        bootstrap: packed::LightClientBootstrap,
+        store: S,
+    ) -> Result<Self, BootstrapError> {
+        let client =
+            core::Client::new_from_bootstrap(&spec, trusted_block_root, bootstrap.as_reader())?;
+
+        let mut mmr = mmr::ClientRootMMR::new(0, &store);
+        let header: core::Header = bootstrap.header().unpack();
+        mmr.push(header.calc_cache().digest())
+            .map_err(|_| BootstrapError::Other)?;
+        mmr.commit().map_err(|_| BootstrapError::Other)?;
+
+        let mut builder = Self::with_client(client, store);
+        builder.spec = spec;
+        builder.sync_committee = bootstrap.current_sync_committee();
+        Ok(builder)
+    }
+
+    /// Creates a builder to update an existed client, the store should contain the headers MMR
+    /// of the client.
+    ///
+    /// The client follows the mainnet, unless another chain spec is set.
+    pub fn with_client(client: core::Client, store: S) -> Self {
+        Self {
+            spec: core::ChainSpec::MAINNET,
+            store,
+            client,
+            headers: Vec::new(),
+            tip_update_opt: None,
+            sync_committee: Default::default(),
+            next_sync_committee_opt: None,
+        }
+    }
+
+    /// The chain spec which the proof updates are verified with.
+    pub fn spec(&self) -> &core::ChainSpec {
+        &self.spec
+    }
+
+    /// Sets the chain spec of the network which the client follows.
+    pub fn set_chain_spec(&mut self, spec: core::ChainSpec) {
+        self.spec = spec;
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// The client which the last built proof update is applied to.
+    pub fn client(&self) -> &core::Client {
+        &self.client
+    }
+
+    /// The headers MMR of the client.
+    pub fn headers_mmr(&self) -> mmr::ClientRootMMR<&S> {
+        let mmr_size = leaf_index_to_mmr_size(self.client.maximal_slot - self.client.minimal_slot);
+        mmr::ClientRootMMR::new(mmr_size, &self.store)
+    }
+
+    /// The new headers which are not built into a proof update yet.
+    pub fn headers(&self) -> &[packed::Header] {
+        &self.headers
+    }
+
+    /// Pushes a new header, the empty slots should be pushed as headers with only the slot set.
+    pub fn push_header(&mut self, header: &BeaconBlockHeader) {
+        let packed_header = packed::Header::from_ssz_header(header);
+        self.headers.push(packed_header);
+    }
+
+    /// The last non-empty header in the new headers, which should be attested.
+    pub fn tip_valid_header(&self) -> Option<&packed::Header> {
+        self.headers.iter().rev().find(|header| {
+            let header: core::Header = header.unpack();
+            !header.is_empty()
+        })
+    }
+
+    /// Sets the finality update for the tip valid header.
+    pub fn set_tip_update(&mut self, update: packed::FinalityUpdate) {
+        self.tip_update_opt = Some(update);
+    }
+
+    /// Sets the sync committee which signs the tip update.
+    ///
+    /// The sync committee is kept for the following proof updates.
+    pub fn set_sync_committee(&mut self, sync_committee: packed::SyncCommittee) {
+        self.sync_committee = sync_committee;
+    }
+
+    /// Sets the next sync committee and its SSZ proof in the attested state of the tip update.
+    pub fn set_next_sync_committee(&mut self, root: Hash256, ssz_proof: Vec<Hash256>) {
+        self.next_sync_committee_opt = Some((root, ssz_proof));
+    }
+
+    /// Builds the proof update for the new headers until the tip valid header, and verifies it
+    /// with the client.
+    ///
+    /// The empty headers after the tip valid header are kept for the next proof update, since
+    /// they couldn't be attested.
+    ///
+    /// If the proof update is valid, the new headers are committed into the headers MMR, and the
+    /// client is updated.
+    /// Otherwise, nothing is changed.
+    pub fn build(&mut self) -> Result<packed::ProofUpdate, ProofUpdateError> {
+        if self.headers.is_empty() {
+            return Err(ProofUpdateError::EmptyUpdates);
+        }
+        let tip_index = self
+            .headers
+            .iter()
+            .rposition(|header| {
+                let header: core::Header = header.unpack();
+                !header.is_empty()
+            })
+            .ok_or(ProofUpdateError::UnattestedTipHeader)?;
+        let headers = &self.headers[..=tip_index];
+
+        let mut mmr = self.headers_mmr();
+        let mut positions = Vec::with_capacity(headers.len());
+        for packed_header in headers {
+            let header: core::Header = packed_header.unpack();
+            let position = mmr
+                .push(header.calc_cache().digest())
+                .map_err(|_| ProofUpdateError::Other)?;
+            positions.push(position);
+        }
+        let new_headers_mmr_root = mmr.get_root().map_err(|_| ProofUpdateError::Other)?;
+        let new_headers_mmr_proof = {
+            let proof_items = mmr
+                .gen_proof(positions)
+                .map_err(|_| ProofUpdateError::Other)?
+                .proof_items()
+                .to_owned();
+            packed::MmrProof::new_builder().set(proof_items).build()
+        };
+
+        // Only the update which contains the tip valid header is attested.
+        let updates = {
+            let items = headers
+                .iter()
+                .enumerate()
+                .map(|(index, header)| match self.tip_update_opt {
+                    Some(ref update) if index == tip_index => update.clone(),
+                    _ => packed::FinalityUpdate::new_builder()
+                        .finalized_header(header.clone())
+                        .build(),
+                })
+                .collect::<Vec<_>>();
+            packed::FinalityUpdateVec::new_builder().set(items).build()
+        };
+
+        let (next_sync_committee_root, next_committee_ssz_proof) =
+            self.next_sync_committee_opt.clone().unwrap_or_default();
+
+        let packed_proof_update = packed::ProofUpdate::new_builder()
+            .new_headers_mmr_root(new_headers_mmr_root)
+            .next_sync_committee_root(next_sync_committee_root.pack())
+            .next_committee_ssz_proof(next_committee_ssz_proof.pack())
+            .new_headers_mmr_proof(new_headers_mmr_proof)
+            .updates(updates)
+            .sync_committee(self.sync_committee.clone())
+            .build();
+
+        let new_client = self
+            .client
+            .try_apply_packed_proof_update(&self.spec, packed_proof_update.as_reader())?;
+
+        mmr.commit().map_err(|_| ProofUpdateError::Other)?;
+        self.client = new_client;
+        self.headers.drain(..=tip_index);
+        self.tip_update_opt = None;
+        self.next_sync_committee_opt = None;
+
+        Ok(packed_proof_update)
+    }
+
+    /// Drops the headers before the last `window_size` slots of the client, and moves the headers
+    /// in the window into a new store, which should be empty.
+    ///
+    /// The following proof updates are built with the new store.
+    pub fn slide_headers_window(
+        &mut self,
+        window_size: u64,
+        new_store: S,
+    ) -> Result<packed::HeadersWindowProof, HeadersWindowError> {
+        let (packed_proof, new_client) =
+            build_headers_window_proof(&self.client, &self.store, window_size)?;
+
+        let mut mmr = mmr::ClientRootMMR::new(0, &new_store);
+        for digest in packed_proof.digests().into_iter() {
+            mmr.push(digest).map_err(|_| HeadersWindowError::Other)?;
+        }
+        mmr.commit().map_err(|_| HeadersWindowError::Other)?;
+
+        self.store = new_store;
+        self.client = new_client;
+
+        Ok(packed_proof)
+    }
+}
@@ -0,0 +1,80 @@
+use eth2_types::EthSpec;
+use ethers_core::types::{EIP1186ProofResponse, U256};
+
+use eth_light_client_in_ckb_verification::{
+    error::StateVerificationError,
+    mmr::{self, lib::MMRStore},
+    types::{core, packed, prelude::*},
+};
+use tree_hash::Hash256;
+
+use crate::{transaction_proof::generate_header_mmr_proof, CachedBeaconBlock};
+
+/// Builds the proof for an account and its storage slots from the response of `eth_getProof`,
+/// and verifies it with the client before returning.
+///
+/// The response should be fetched at the execution block of the beacon block.
+/// The headers MMR should be the same as the one in the client.
+pub fn build_state_proof<T, S>(
+    spec: &core::ChainSpec,
+    client: &core::Client,
+    headers_mmr: &mmr::ClientRootMMR<S>,
+    block: &CachedBeaconBlock<T>,
+    response: &EIP1186ProofResponse,
+) -> Result<packed::StateProof, StateVerificationError>
+where
+    T: EthSpec,
+    S: MMRStore<packed::HeaderDigest>,
+{
+    let slot: u64 = block.slot().into();
+    if slot < client.minimal_slot || slot > client.maximal_slot {
+        return Err(StateVerificationError::Unsynchronized);
+    }
+
+    let header: core::Header = packed::Header::from_ssz_header(&block.header()).unpack();
+    let header_mmr_proof = generate_header_mmr_proof(client, headers_mmr, slot)
+        .map_err(|_| StateVerificationError::HeaderMmrProof)?;
+
+    let storage_proofs = response
+        .storage_proof
+        .iter()
+        .map(|storage_proof| core::StorageProof {
+            key: Hash256::from_slice(storage_proof.key.as_bytes()),
+            value: to_big_endian(storage_proof.value),
+            proof: storage_proof
+                .proof
+                .iter()
+                .map(|node| node.to_vec())
+                .collect(),
+        })
+        .collect();
+    let proof = core::StateProof {
+        header,
+        state_root: block.execution_state_root(),
+        header_mmr_proof,
+        state_root_ssz_proof: block.generate_state_root_proof_for_block_body(),
+        address: response.address.to_fixed_bytes(),
+        nonce: response.nonce.as_u64(),
+        balance: to_big_endian(response.balance),
+        storage_root: Hash256::from_slice(response.storage_hash.as_bytes()),
+        code_hash: Hash256::from_slice(response.code_hash.as_bytes()),
+        account_mpt_proof: response
+            .account_proof
+            .iter()
+            .map(|node| node.to_vec())
+            .collect(),
+        storage_proofs,
+    };
+
+    let packed_proof = proof.pack();
+
+    client.verify_packed_state_proof(spec, packed_proof.as_reader())?;
+
+    Ok(packed_proof)
+}
+
+fn to_big_endian(value: U256) -> core::Uint256 {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+    bytes
+}
@@ -0,0 +1,144 @@
+use eth2_types::EthSpec;
+
+use eth_light_client_in_ckb_verification::{
+    error::TxVerificationError,
+    mmr::{
+        self,
+        lib::{leaf_index_to_pos, MMRStore},
+    },
+    types::{core, packed, prelude::*},
+};
+
+use crate::{CachedBeaconBlock, Receipts};
+
+/// Builds the proof and the payload for a transaction in the block, and verifies them with the
+/// client before returning.
+///
+/// The headers MMR should be the same as the one in the client.
+pub fn build_transaction_proof<T, S>(
+    spec: &core::ChainSpec,
+    client: &core::Client,
+    headers_mmr: &mmr::ClientRootMMR<S>,
+    block: &CachedBeaconBlock<T>,
+    receipts: &Receipts,
+    index: usize,
+) -> Result<(packed::TransactionProof, packed::TransactionPayload), TxVerificationError>
+where
+    T: EthSpec,
+    S: MMRStore<packed::HeaderDigest>,
+{
+    if index >= block.transactions_count() || index >= receipts.original().len() {
+        return Err(TxVerificationError::Other);
+    }
+
+    let header: core::Header = packed::Header::from_ssz_header(&block.header()).unpack();
+    let header_mmr_proof = generate_header_mmr_proof(client, headers_mmr, block.slot().into())?;
+
+    let proof = core::TransactionProof {
+        header,
+        transaction_index: index as u64,
+        receipts_root: receipts.root(),
+        header_mmr_proof,
+        transaction_ssz_proof: block.generate_transaction_proof_for_block_body(index),
+        receipt_mpt_proof: receipts.generate_proof(index),
+        receipts_root_ssz_proof: block.generate_receipts_root_proof_for_block_body(),
+    };
+    let payload = core::TransactionPayload {
+        transaction: block.transaction(index).unwrap().to_vec(),
+        receipt: receipts.encode_data(index),
+    };
+
+    let packed_proof = proof.pack();
+    let packed_payload = payload.pack();
+
+    client.verify_packed_transaction_proof(packed_proof.as_reader())?;
+    proof.verify_packed_payload(spec, packed_payload.as_reader())?;
+
+    Ok((packed_proof, packed_payload))
+}
+
+/// Builds the proof and the payloads for several transactions in the block, which share the proofs
+/// for the block, and verifies them with the client before returning.
+///
+/// The headers MMR should be the same as the one in the client.
+pub fn build_block_transactions_proof<T, S>(
+    spec: &core::ChainSpec,
+    client: &core::Client,
+    headers_mmr: &mmr::ClientRootMMR<S>,
+    block: &CachedBeaconBlock<T>,
+    receipts: &Receipts,
+    indexes: &[usize],
+) -> Result<
+    (
+        packed::BlockTransactionsProof,
+        packed::TransactionPayloadVec,
+    ),
+    TxVerificationError,
+>
+where
+    T: EthSpec,
+    S: MMRStore<packed::HeaderDigest>,
+{
+    if indexes
+        .iter()
+        .any(|index| *index >= block.transactions_count() || *index >= receipts.original().len())
+    {
+        return Err(TxVerificationError::Other);
+    }
+
+    let header: core::Header = packed::Header::from_ssz_header(&block.header()).unpack();
+    let header_mmr_proof = generate_header_mmr_proof(client, headers_mmr, block.slot().into())?;
+
+    let transactions = indexes
+        .iter()
+        .map(|index| core::TransactionInBlockProof {
+            transaction_index: *index as u64,
+            transaction_ssz_proof: block.generate_transaction_proof_for_block_body(*index),
+            receipt_mpt_proof: receipts.generate_proof(*index),
+        })
+        .collect();
+    let proof = core::BlockTransactionsProof {
+        header,
+        receipts_root: receipts.root(),
+        header_mmr_proof,
+        receipts_root_ssz_proof: block.generate_receipts_root_proof_for_block_body(),
+        transactions,
+    };
+    let payloads = indexes
+        .iter()
+        .map(|index| core::TransactionPayload {
+            transaction: block.transaction(*index).unwrap().to_vec(),
+            receipt: receipts.encode_data(*index),
+        })
+        .collect::<Vec<_>>();
+
+    let packed_proof = proof.pack();
+    let packed_payloads = payloads.pack();
+
+    client.verify_packed_block_transactions_proof(packed_proof.as_reader())?;
+    proof.verify_packed_payloads(spec, packed_payloads.as_reader())?;
+
+    Ok((packed_proof, packed_payloads))
+}
+
+pub(crate) fn generate_header_mmr_proof<S>(
+    client: &core::Client,
+    headers_mmr: &mmr::ClientRootMMR<S>,
+    slot: u64,
+) -> Result<core::MmrProof, TxVerificationError>
+where
+    S: MMRStore<packed::HeaderDigest>,
+{
+    if slot < client.minimal_slot || slot > client.maximal_slot {
+        return Err(TxVerificationError::Unsynchronized);
+    }
+    let position = leaf_index_to_pos(slot - client.minimal_slot);
+    let proof = headers_mmr
+        .gen_proof(vec![position])
+        .map_err(|_| TxVerificationError::HeaderMmrProof)?
+        .proof_items()
+        .iter()
+        .map(|item| item.unpack())
+        .collect();
+    Ok(proof)
+}
@@ -0,0 +1,62 @@
+use eth2_types::EthSpec;
+
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::helpers::Fork,
+    error::WithdrawalVerificationError,
+    mmr::{self, lib::MMRStore},
+    types::{core, packed, prelude::*},
+};
+
+use crate::{transaction_proof::generate_header_mmr_proof, CachedBeaconBlock};
+
+/// Builds the proof for a withdrawal in the block, and verifies it with the client before
+/// returning.
+///
+/// The index is the position of the withdrawal in the execution payload, not the global index of
+/// the withdrawal.
+/// The headers MMR should be the same as the one in the client.
+pub fn build_withdrawal_proof<T, S>(
+    spec: &core::ChainSpec,
+    client: &core::Client,
+    headers_mmr: &mmr::ClientRootMMR<S>,
+    block: &CachedBeaconBlock<T>,
+    index: usize,
+) -> Result<packed::WithdrawalProof, WithdrawalVerificationError>
+where
+    T: EthSpec,
+    S: MMRStore<packed::HeaderDigest>,
+{
+    let slot: u64 = block.slot().into();
+    if slot < client.minimal_slot || slot > client.maximal_slot {
+        return Err(WithdrawalVerificationError::Unsynchronized);
+    }
+    if block.fork() < Fork::Capella {
+        return Err(WithdrawalVerificationError::BeforeCapella);
+    }
+    let withdrawal = block
+        .withdrawal(index)
+        .ok_or(WithdrawalVerificationError::WithdrawalIndex)?;
+
+    let header: core::Header = packed::Header::from_ssz_header(&block.header()).unpack();
+    let header_mmr_proof = generate_header_mmr_proof(client, headers_mmr, slot)
+        .map_err(|_| WithdrawalVerificationError::HeaderMmrProof)?;
+
+    let proof = core::WithdrawalProof {
+        header,
+        withdrawal_index: index as u64,
+        withdrawal: core::Withdrawal {
+            index: withdrawal.index,
+            validator_index: withdrawal.validator_index,
+            address: withdrawal.address.to_fixed_bytes(),
+            amount: withdrawal.amount,
+        },
+        header_mmr_proof,
+        withdrawal_ssz_proof: block.generate_withdrawal_proof(index),
+    };
+
+    let packed_proof = proof.pack();
+
+    client.verify_packed_withdrawal_proof(spec, packed_proof.as_reader())?;
+
+    Ok(packed_proof)
+}
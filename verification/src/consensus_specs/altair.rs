@@ -2,12 +2,53 @@
 //!
 //! [Altair]: https://github.com/ethereum/consensus-specs/tree/v1.1.0/specs/altair
 
+use crate::ssz::floor_depth;
+
 /// From [Fork Logic / Configuration].
 ///
 /// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/fork.md#configuration
 pub const FORK_EPOCH: u64 = 74240;
 
+/// From [Fork Logic / Configuration].
+///
+/// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/fork.md#configuration
+pub const FORK_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+/// From [The Beacon Chain / Constants / Domain types].
+///
+/// [The Beacon Chain / Constants / Domain types]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/beacon-chain.md#domain-types
+pub const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [0x07, 0x00, 0x00, 0x00];
+
 /// From [The Beacon Chain / Preset / Sync committee].
 ///
 /// [The Beacon Chain / Preset / Sync committee]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/beacon-chain.md#sync-committee
 pub const SYNC_COMMITTEE_SIZE: usize = 512;
+
+/// From [The Beacon Chain / Preset / Sync committee].
+///
+/// [The Beacon Chain / Preset / Sync committee]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/beacon-chain.md#sync-committee
+pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
+
+/// From [Minimal Light Client / Constants].
+///
+/// [Minimal Light Client / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/sync-protocol.md#constants
+pub const FINALIZED_ROOT_INDEX: usize = 105;
+
+/// The depth of [`FINALIZED_ROOT_INDEX`].
+pub const FINALIZED_ROOT_DEPTH: u32 = floor_depth(FINALIZED_ROOT_INDEX);
+
+/// From [Light Client / Sync Protocol / Constants].
+///
+/// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#constants
+pub const CURRENT_SYNC_COMMITTEE_INDEX: usize = 54;
+
+/// The depth of [`CURRENT_SYNC_COMMITTEE_INDEX`].
+pub const CURRENT_SYNC_COMMITTEE_DEPTH: u32 = floor_depth(CURRENT_SYNC_COMMITTEE_INDEX);
+
+/// From [Minimal Light Client / Constants].
+///
+/// [Minimal Light Client / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/sync-protocol.md#constants
+pub const NEXT_SYNC_COMMITTEE_INDEX: usize = 55;
+
+/// The depth of [`NEXT_SYNC_COMMITTEE_INDEX`].
+pub const NEXT_SYNC_COMMITTEE_DEPTH: u32 = floor_depth(NEXT_SYNC_COMMITTEE_INDEX);
@@ -9,7 +9,17 @@ use super::altair as previous_fork;
 /// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/fork.md#configuration
 pub const FORK_EPOCH: u64 = 144896;
 
-pub use previous_fork::SYNC_COMMITTEE_SIZE;
+/// From [Fork Logic / Configuration].
+///
+/// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/fork.md#configuration
+pub const FORK_VERSION: [u8; 4] = [0x02, 0x00, 0x00, 0x00];
+
+pub use previous_fork::{EPOCHS_PER_SYNC_COMMITTEE_PERIOD, SYNC_COMMITTEE_SIZE};
+
+pub use previous_fork::{
+    CURRENT_SYNC_COMMITTEE_DEPTH, CURRENT_SYNC_COMMITTEE_INDEX, FINALIZED_ROOT_DEPTH,
+    FINALIZED_ROOT_INDEX, NEXT_SYNC_COMMITTEE_DEPTH, NEXT_SYNC_COMMITTEE_INDEX,
+};
 
 /// From [The Beacon Chain / Preset / Execution].
 ///
@@ -32,11 +42,41 @@ pub mod containers {
     /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
     pub const EXECUTION_PAYLOAD_FIELDS_COUNT: usize = 14;
 
+    /// `state_root` is the 3-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
+    pub const STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX: usize = 2;
+
     /// `receipts_root` is the 4-th field in [`ExecutionPayload`].
     ///
     /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
     pub const RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX: usize = 3;
 
+    /// `logs_bloom` is the 5-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
+    pub const LOGS_BLOOM_IN_EXECUTION_PAYLOAD_INDEX: usize = 4;
+
+    /// `block_number` is the 7-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
+    pub const BLOCK_NUMBER_IN_EXECUTION_PAYLOAD_INDEX: usize = 6;
+
+    /// `timestamp` is the 10-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
+    pub const TIMESTAMP_IN_EXECUTION_PAYLOAD_INDEX: usize = 9;
+
+    /// `base_fee_per_gas` is the 12-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
+    pub const BASE_FEE_PER_GAS_IN_EXECUTION_PAYLOAD_INDEX: usize = 11;
+
+    /// `block_hash` is the 13-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
+    pub const BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX: usize = 12;
+
     /// `transactions` is the 14-th field in [`ExecutionPayload`].
     ///
     /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#executionpayload
@@ -2,6 +2,8 @@
 //!
 //! [Capella]: https://github.com/ethereum/consensus-specs/tree/v1.3.0/specs/capella
 
+use crate::ssz::floor_depth;
+
 use super::bellatrix as previous_fork;
 
 /// From [Fork Logic / Configuration].
@@ -9,33 +11,104 @@ use super::bellatrix as previous_fork;
 /// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/fork.md#configuration
 pub const FORK_EPOCH: u64 = 194048;
 
-pub use previous_fork::SYNC_COMMITTEE_SIZE;
+/// From [Fork Logic / Configuration].
+///
+/// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/fork.md#configuration
+pub const FORK_VERSION: [u8; 4] = [0x03, 0x00, 0x00, 0x00];
+
+pub use previous_fork::{EPOCHS_PER_SYNC_COMMITTEE_PERIOD, SYNC_COMMITTEE_SIZE};
+
+pub use previous_fork::{
+    CURRENT_SYNC_COMMITTEE_DEPTH, CURRENT_SYNC_COMMITTEE_INDEX, FINALIZED_ROOT_DEPTH,
+    FINALIZED_ROOT_INDEX, NEXT_SYNC_COMMITTEE_DEPTH, NEXT_SYNC_COMMITTEE_INDEX,
+};
+
+/// Generalized index for `block_roots` in `BeaconState`.
+pub const BLOCK_ROOTS_INDEX: usize = 37;
+
+/// The depth of [`BLOCK_ROOTS_INDEX`].
+pub const BLOCK_ROOTS_DEPTH: u32 = floor_depth(BLOCK_ROOTS_INDEX);
+
+/// Generalized index for `historical_summaries` in `BeaconState`.
+///
+/// The field is introduced in [The Beacon Chain / Containers / Modified containers].
+///
+/// [The Beacon Chain / Containers / Modified containers]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#beaconstate
+pub const HISTORICAL_SUMMARIES_INDEX: usize = 59;
+
+/// The depth of [`HISTORICAL_SUMMARIES_INDEX`].
+pub const HISTORICAL_SUMMARIES_DEPTH: u32 = floor_depth(HISTORICAL_SUMMARIES_INDEX);
 
 pub use previous_fork::MAX_BYTES_PER_TRANSACTION;
 
 pub use previous_fork::MAX_TRANSACTIONS_PER_PAYLOAD;
 
+/// From [The Beacon Chain / Preset / Execution].
+///
+/// [The Beacon Chain / Preset / Execution]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#execution
+pub const MAX_WITHDRAWALS_PER_PAYLOAD: usize = 16;
+
 /// Constants for containers.
 pub mod containers {
     use crate::ssz::ceil_depth;
 
-    use super::MAX_TRANSACTIONS_PER_PAYLOAD;
+    use super::{MAX_TRANSACTIONS_PER_PAYLOAD, MAX_WITHDRAWALS_PER_PAYLOAD};
 
     /// There are 15 fields in [`ExecutionPayload`].
     ///
     /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
     pub const EXECUTION_PAYLOAD_FIELDS_COUNT: usize = 15;
 
+    /// `state_root` is the 3-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
+    pub const STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX: usize = 2;
+
     /// `receipts_root` is the 4-th field in [`ExecutionPayload`].
     ///
     /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
     pub const RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX: usize = 3;
 
+    /// `logs_bloom` is the 5-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
+    pub const LOGS_BLOOM_IN_EXECUTION_PAYLOAD_INDEX: usize = 4;
+
+    /// `block_number` is the 7-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
+    pub const BLOCK_NUMBER_IN_EXECUTION_PAYLOAD_INDEX: usize = 6;
+
+    /// `timestamp` is the 10-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
+    pub const TIMESTAMP_IN_EXECUTION_PAYLOAD_INDEX: usize = 9;
+
+    /// `base_fee_per_gas` is the 12-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
+    pub const BASE_FEE_PER_GAS_IN_EXECUTION_PAYLOAD_INDEX: usize = 11;
+
+    /// `block_hash` is the 13-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
+    pub const BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX: usize = 12;
+
     /// `transactions` is the 14-th field in [`ExecutionPayload`].
     ///
     /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
     pub const TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX: usize = 13;
 
+    /// `withdrawals` is the 15-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
+    pub const WITHDRAWALS_IN_EXECUTION_PAYLOAD_INDEX: usize = 14;
+
+    /// There are 4 fields in [`Withdrawal`].
+    ///
+    /// [`Withdrawal`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#withdrawal
+    pub const WITHDRAWAL_FIELDS_COUNT: usize = 4;
+
     /// There are 11 fields in [`BeaconBlockBody`].
     ///
     /// [`BeaconBlockBody`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#beaconblockbody
@@ -48,10 +121,14 @@ pub mod containers {
 
     /// The depth of [`MAX_TRANSACTIONS_PER_PAYLOAD`].
     pub const TRANSACTIONS_DEPTH: u32 = ceil_depth(MAX_TRANSACTIONS_PER_PAYLOAD);
+    /// The depth of [`MAX_WITHDRAWALS_PER_PAYLOAD`].
+    pub const WITHDRAWALS_DEPTH: u32 = ceil_depth(MAX_WITHDRAWALS_PER_PAYLOAD);
+    /// The depth of [`WITHDRAWAL_FIELDS_COUNT`].
+    pub const WITHDRAWAL_DEPTH: u32 = ceil_depth(WITHDRAWAL_FIELDS_COUNT);
     /// The depth of [`EXECUTION_PAYLOAD_FIELDS_COUNT`].
     pub const EXECUTION_PAYLOAD_DEPTH: u32 = ceil_depth(EXECUTION_PAYLOAD_FIELDS_COUNT);
     /// The depth of [`BLOCK_BODY_FIELDS_COUNT`].
     pub const BLOCK_BODY_DEPTH: u32 = ceil_depth(BLOCK_BODY_FIELDS_COUNT);
 }
 
-define_generalized_index_mod!(super::containers);
+define_generalized_index_mod!(super::containers, with_withdrawals);
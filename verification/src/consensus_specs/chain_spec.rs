@@ -0,0 +1,258 @@
+//! Configurations of beacon chain networks, and the helper functions which depend on them.
+//!
+//! The fork epochs, the fork versions and the genesis validators root are different in each
+//! network, so they are not constants; the client commits the one it follows in its type args.
+//! For a local devnet, the fork epochs which are not scheduled should be
+//! [`phase0::FAR_FUTURE_EPOCH`], and the preset is usually [`Preset::MINIMAL`].
+//!
+//! The forks are scheduled in the order of [`Fork::SCHEDULED`], so a new fork is supported by
+//! appending its configuration.
+
+use tree_hash::Hash256;
+
+use super::{altair, bellatrix, capella, deneb, electra, fulu, helpers::Fork, phase0};
+use crate::{
+    ssz,
+    types::{
+        core::{ChainSpec, ForkConfig, Preset},
+        packed,
+        prelude::*,
+    },
+};
+
+impl ForkConfig {
+    /// The configuration of a fork which isn't scheduled.
+    pub const UNSCHEDULED: Self = Self {
+        version: [0; 4],
+        epoch: phase0::FAR_FUTURE_EPOCH,
+    };
+}
+
+impl Preset {
+    /// The [`mainnet`] preset.
+    ///
+    /// [`mainnet`]: https://github.com/ethereum/consensus-specs/tree/v1.5.0/presets/mainnet
+    pub const MAINNET: Self = Self {
+        slots_per_epoch: phase0::SLOTS_PER_EPOCH,
+        epochs_per_sync_committee_period: altair::EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+        sync_committee_size: altair::SYNC_COMMITTEE_SIZE as u64,
+        max_withdrawals_per_payload: capella::MAX_WITHDRAWALS_PER_PAYLOAD as u64,
+        slots_per_historical_root: phase0::SLOTS_PER_HISTORICAL_ROOT,
+    };
+
+    /// The [`minimal`] preset, which is usually used by local devnets.
+    ///
+    /// [`minimal`]: https://github.com/ethereum/consensus-specs/tree/v1.5.0/presets/minimal
+    pub const MINIMAL: Self = Self {
+        slots_per_epoch: 8,
+        epochs_per_sync_committee_period: 8,
+        sync_committee_size: 32,
+        max_withdrawals_per_payload: 4,
+        slots_per_historical_root: 64,
+    };
+
+    /// Checks whether the preset could be used by the helpers, since it's from the type args of
+    /// the client cells.
+    ///
+    /// The values are divisors or sizes of SSZ lists, so none of them should be 0, and the slots
+    /// in a sync committee period should not overflow.
+    pub const fn is_valid(&self) -> bool {
+        self.slots_per_epoch != 0
+            && self.epochs_per_sync_committee_period != 0
+            && self.sync_committee_size != 0
+            && self.max_withdrawals_per_payload != 0
+            && self.slots_per_historical_root != 0
+            && self
+                .slots_per_epoch
+                .checked_mul(self.epochs_per_sync_committee_period)
+                .is_some()
+    }
+
+    /// The depth of the withdrawals in an execution payload, since Capella.
+    pub const fn withdrawals_depth(&self) -> u32 {
+        ssz::ceil_depth(self.max_withdrawals_per_payload as usize)
+    }
+
+    /// The depth of the block roots in a beacon state, or in a historical summary.
+    pub const fn block_roots_depth(&self) -> u32 {
+        ssz::ceil_depth(self.slots_per_historical_root as usize)
+    }
+
+    /// The count of slots in a sync committee period.
+    pub const fn slots_per_sync_committee_period(&self) -> u64 {
+        self.slots_per_epoch * self.epochs_per_sync_committee_period
+    }
+}
+
+impl Default for ChainSpec {
+    /// The Ethereum mainnet, which most clients follow.
+    fn default() -> Self {
+        Self::MAINNET
+    }
+}
+
+impl ChainSpec {
+    /// The Ethereum mainnet.
+    pub const MAINNET: Self = Self {
+        genesis_validators_root: Hash256(phase0::GENESIS_VALIDATORS_ROOT),
+        genesis_fork_version: phase0::GENESIS_FORK_VERSION,
+        preset: Preset::MAINNET,
+        forks: [
+            ForkConfig {
+                version: altair::FORK_VERSION,
+                epoch: altair::FORK_EPOCH,
+            },
+            ForkConfig {
+                version: bellatrix::FORK_VERSION,
+                epoch: bellatrix::FORK_EPOCH,
+            },
+            ForkConfig {
+                version: capella::FORK_VERSION,
+                epoch: capella::FORK_EPOCH,
+            },
+            ForkConfig {
+                version: deneb::FORK_VERSION,
+                epoch: deneb::FORK_EPOCH,
+            },
+            ForkConfig {
+                version: electra::FORK_VERSION,
+                epoch: electra::FORK_EPOCH,
+            },
+            ForkConfig {
+                version: fulu::FORK_VERSION,
+                epoch: fulu::FORK_EPOCH,
+            },
+        ],
+    };
+
+    /// The Sepolia testnet.
+    pub const SEPOLIA: Self = Self {
+        genesis_validators_root: Hash256([
+            0xd8, 0xea, 0x17, 0x1f, 0x3c, 0x94, 0xae, 0xa2, 0x1e, 0xbc, 0x42, 0xa1, 0xed, 0x61,
+            0x05, 0x2a, 0xcf, 0x3f, 0x92, 0x09, 0xc0, 0x0e, 0x4e, 0xfb, 0xaa, 0xdd, 0xac, 0x09,
+            0xed, 0x9b, 0x80, 0x78,
+        ]),
+        genesis_fork_version: [0x90, 0x00, 0x00, 0x69],
+        preset: Preset::MAINNET,
+        forks: [
+            ForkConfig {
+                version: [0x90, 0x00, 0x00, 0x70],
+                epoch: 50,
+            },
+            ForkConfig {
+                version: [0x90, 0x00, 0x00, 0x71],
+                epoch: 100,
+            },
+            ForkConfig {
+                version: [0x90, 0x00, 0x00, 0x72],
+                epoch: 56832,
+            },
+            ForkConfig {
+                version: [0x90, 0x00, 0x00, 0x73],
+                epoch: 132608,
+            },
+            ForkConfig {
+                version: [0x90, 0x00, 0x00, 0x74],
+                epoch: 222464,
+            },
+            ForkConfig {
+                version: [0x90, 0x00, 0x00, 0x75],
+                epoch: 272640,
+            },
+        ],
+    };
+
+    /// See [`compute_epoch_at_slot`].
+    ///
+    /// [`compute_epoch_at_slot`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#compute_epoch_at_slot
+    pub const fn compute_epoch_at_slot(&self, slot: u64) -> u64 {
+        slot / self.preset.slots_per_epoch
+    }
+
+    /// See [`compute_start_slot_at_epoch`].
+    ///
+    /// [`compute_start_slot_at_epoch`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#compute_start_slot_at_epoch
+    pub const fn compute_start_slot_at_epoch(&self, epoch: u64) -> u64 {
+        epoch * self.preset.slots_per_epoch
+    }
+
+    /// See [`compute_sync_committee_period`].
+    ///
+    /// [`compute_sync_committee_period`]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/validator.md#sync-committee
+    pub const fn compute_sync_committee_period(&self, epoch: u64) -> u64 {
+        epoch / self.preset.epochs_per_sync_committee_period
+    }
+
+    /// See [`compute_sync_committee_period_at_slot`].
+    ///
+    /// [`compute_sync_committee_period_at_slot`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#compute_sync_committee_period_at_slot
+    pub const fn compute_sync_committee_period_at_slot(&self, slot: u64) -> u64 {
+        self.compute_sync_committee_period(self.compute_epoch_at_slot(slot))
+    }
+
+    /// Checks whether the chain spec could be used by the helpers, since it's from the type args
+    /// of the client cells.
+    ///
+    /// The preset should be valid, and the forks should be scheduled in order.
+    pub const fn is_valid(&self) -> bool {
+        if !self.preset.is_valid() {
+            return false;
+        }
+        let mut index = 1;
+        while index < self.forks.len() {
+            if self.forks[index].epoch < self.forks[index - 1].epoch {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+
+    /// Returns the configuration of the fork.
+    ///
+    /// The genesis fork version is returned for [`Fork::Phase0`], which is activated at the
+    /// genesis.
+    pub const fn fork_config(&self, fork: Fork) -> ForkConfig {
+        match fork {
+            Fork::Phase0 => ForkConfig {
+                version: self.genesis_fork_version,
+                epoch: 0,
+            },
+            // The scheduled forks follow Phase0 in order.
+            _ => self.forks[fork as usize - 1],
+        }
+    }
+
+    /// Computes the fork which the epoch belongs to, it's the last fork which is activated.
+    pub const fn compute_fork_at_epoch(&self, epoch: u64) -> Fork {
+        let mut index = self.forks.len();
+        while index > 0 {
+            index -= 1;
+            if epoch >= self.forks[index].epoch {
+                return Fork::SCHEDULED[index];
+            }
+        }
+        Fork::Phase0
+    }
+
+    /// Computes the fork which the slot belongs to.
+    pub const fn compute_fork_at_slot(&self, slot: u64) -> Fork {
+        self.compute_fork_at_epoch(self.compute_epoch_at_slot(slot))
+    }
+
+    /// See [`compute_fork_version`].
+    ///
+    /// [`compute_fork_version`]: https://github.com/ethereum/consensus-specs/blob/v1.6.0/specs/fulu/fork.md#modified-compute_fork_version
+    pub const fn compute_fork_version(&self, epoch: u64) -> [u8; 4] {
+        self.fork_config(self.compute_fork_at_epoch(epoch)).version
+    }
+}
+
+impl<'r> packed::ChainSpecReader<'r> {
+    /// Checks whether the chain spec could be used by the helpers, see [`ChainSpec::is_valid`].
+    ///
+    /// The forks which aren't known can't be verified, so they should not be in the chain spec.
+    pub fn is_valid(&self) -> bool {
+        self.forks().len() <= Fork::SCHEDULED.len() && self.unpack().is_valid()
+    }
+}
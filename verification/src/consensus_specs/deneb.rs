@@ -0,0 +1,141 @@
+//! Beacon chain hard fork: [Deneb].
+//!
+//! [Deneb]: https://github.com/ethereum/consensus-specs/tree/v1.4.0/specs/deneb
+
+use super::capella as previous_fork;
+
+/// From [Fork Logic / Configuration].
+///
+/// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/fork.md#configuration
+pub const FORK_EPOCH: u64 = 269568;
+
+/// From [Fork Logic / Configuration].
+///
+/// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/fork.md#configuration
+pub const FORK_VERSION: [u8; 4] = [0x04, 0x00, 0x00, 0x00];
+
+pub use previous_fork::{EPOCHS_PER_SYNC_COMMITTEE_PERIOD, SYNC_COMMITTEE_SIZE};
+
+pub use previous_fork::{
+    CURRENT_SYNC_COMMITTEE_DEPTH, CURRENT_SYNC_COMMITTEE_INDEX, FINALIZED_ROOT_DEPTH,
+    FINALIZED_ROOT_INDEX, NEXT_SYNC_COMMITTEE_DEPTH, NEXT_SYNC_COMMITTEE_INDEX,
+};
+
+pub use previous_fork::{
+    BLOCK_ROOTS_DEPTH, BLOCK_ROOTS_INDEX, HISTORICAL_SUMMARIES_DEPTH, HISTORICAL_SUMMARIES_INDEX,
+};
+
+pub use previous_fork::MAX_BYTES_PER_TRANSACTION;
+
+pub use previous_fork::MAX_TRANSACTIONS_PER_PAYLOAD;
+
+pub use previous_fork::MAX_WITHDRAWALS_PER_PAYLOAD;
+
+/// From [The Beacon Chain / Preset / Execution].
+///
+/// [The Beacon Chain / Preset / Execution]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#execution
+pub const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize = 4096;
+
+/// Constants for containers.
+pub mod containers {
+    use crate::ssz::ceil_depth;
+
+    use super::{
+        MAX_BLOB_COMMITMENTS_PER_BLOCK, MAX_TRANSACTIONS_PER_PAYLOAD, MAX_WITHDRAWALS_PER_PAYLOAD,
+    };
+
+    /// There are 17 fields in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const EXECUTION_PAYLOAD_FIELDS_COUNT: usize = 17;
+
+    /// `state_root` is the 3-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX: usize = 2;
+
+    /// `receipts_root` is the 4-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX: usize = 3;
+
+    /// `logs_bloom` is the 5-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const LOGS_BLOOM_IN_EXECUTION_PAYLOAD_INDEX: usize = 4;
+
+    /// `block_number` is the 7-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const BLOCK_NUMBER_IN_EXECUTION_PAYLOAD_INDEX: usize = 6;
+
+    /// `timestamp` is the 10-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const TIMESTAMP_IN_EXECUTION_PAYLOAD_INDEX: usize = 9;
+
+    /// `base_fee_per_gas` is the 12-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const BASE_FEE_PER_GAS_IN_EXECUTION_PAYLOAD_INDEX: usize = 11;
+
+    /// `block_hash` is the 13-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX: usize = 12;
+
+    /// `transactions` is the 14-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX: usize = 13;
+
+    /// `withdrawals` is the 15-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const WITHDRAWALS_IN_EXECUTION_PAYLOAD_INDEX: usize = 14;
+
+    /// `blob_gas_used` is the 16-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const BLOB_GAS_USED_IN_EXECUTION_PAYLOAD_INDEX: usize = 15;
+
+    /// `excess_blob_gas` is the 17-th field in [`ExecutionPayload`].
+    ///
+    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#executionpayload
+    pub const EXCESS_BLOB_GAS_IN_EXECUTION_PAYLOAD_INDEX: usize = 16;
+
+    /// There are 4 fields in [`Withdrawal`].
+    ///
+    /// [`Withdrawal`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#withdrawal
+    pub const WITHDRAWAL_FIELDS_COUNT: usize = 4;
+
+    /// There are 12 fields in [`BeaconBlockBody`].
+    ///
+    /// [`BeaconBlockBody`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#beaconblockbody
+    pub const BLOCK_BODY_FIELDS_COUNT: usize = 12;
+
+    /// `execution_payload` is the 10-th field in [`BeaconBlockBody`].
+    ///
+    /// [`BeaconBlockBody`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#beaconblockbody
+    pub const EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX: usize = 9;
+
+    /// `blob_kzg_commitments` is the 12-th field in [`BeaconBlockBody`].
+    ///
+    /// [`BeaconBlockBody`]: https://github.com/ethereum/consensus-specs/blob/v1.4.0/specs/deneb/beacon-chain.md#beaconblockbody
+    pub const BLOB_KZG_COMMITMENTS_IN_BLOCK_BODY_INDEX: usize = 11;
+
+    /// The depth of [`MAX_TRANSACTIONS_PER_PAYLOAD`].
+    pub const TRANSACTIONS_DEPTH: u32 = ceil_depth(MAX_TRANSACTIONS_PER_PAYLOAD);
+    /// The depth of [`MAX_WITHDRAWALS_PER_PAYLOAD`].
+    pub const WITHDRAWALS_DEPTH: u32 = ceil_depth(MAX_WITHDRAWALS_PER_PAYLOAD);
+    /// The depth of [`WITHDRAWAL_FIELDS_COUNT`].
+    pub const WITHDRAWAL_DEPTH: u32 = ceil_depth(WITHDRAWAL_FIELDS_COUNT);
+    /// The depth of [`MAX_BLOB_COMMITMENTS_PER_BLOCK`].
+    pub const BLOB_KZG_COMMITMENTS_DEPTH: u32 = ceil_depth(MAX_BLOB_COMMITMENTS_PER_BLOCK);
+    /// The depth of [`EXECUTION_PAYLOAD_FIELDS_COUNT`].
+    pub const EXECUTION_PAYLOAD_DEPTH: u32 = ceil_depth(EXECUTION_PAYLOAD_FIELDS_COUNT);
+    /// The depth of [`BLOCK_BODY_FIELDS_COUNT`].
+    pub const BLOCK_BODY_DEPTH: u32 = ceil_depth(BLOCK_BODY_FIELDS_COUNT);
+}
+
+define_generalized_index_mod!(super::containers, with_withdrawals);
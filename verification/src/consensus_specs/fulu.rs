@@ -0,0 +1,39 @@
+//! Beacon chain hard fork: [Fulu].
+//!
+//! [Fulu]: https://github.com/ethereum/consensus-specs/tree/v1.6.0/specs/fulu
+
+use super::electra as previous_fork;
+
+/// From [Fork Logic / Configuration].
+///
+/// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.6.0/specs/fulu/fork.md#configuration
+pub const FORK_EPOCH: u64 = 411392;
+
+/// From [Fork Logic / Configuration].
+///
+/// [Fork Logic / Configuration]: https://github.com/ethereum/consensus-specs/blob/v1.6.0/specs/fulu/fork.md#configuration
+pub const FORK_VERSION: [u8; 4] = [0x06, 0x00, 0x00, 0x00];
+
+pub use previous_fork::{EPOCHS_PER_SYNC_COMMITTEE_PERIOD, SYNC_COMMITTEE_SIZE};
+
+// `proposer_lookahead` is appended to `BeaconState`, the fields are still in the same depth, so
+// the generalized indices are not changed.
+pub use previous_fork::{
+    CURRENT_SYNC_COMMITTEE_DEPTH, CURRENT_SYNC_COMMITTEE_INDEX, FINALIZED_ROOT_DEPTH,
+    FINALIZED_ROOT_INDEX, NEXT_SYNC_COMMITTEE_DEPTH, NEXT_SYNC_COMMITTEE_INDEX,
+};
+
+pub use previous_fork::{
+    BLOCK_ROOTS_DEPTH, BLOCK_ROOTS_INDEX, HISTORICAL_SUMMARIES_DEPTH, HISTORICAL_SUMMARIES_INDEX,
+};
+
+pub use previous_fork::MAX_BYTES_PER_TRANSACTION;
+
+pub use previous_fork::MAX_TRANSACTIONS_PER_PAYLOAD;
+
+pub use previous_fork::MAX_WITHDRAWALS_PER_PAYLOAD;
+
+pub use previous_fork::MAX_BLOB_COMMITMENTS_PER_BLOCK;
+
+// `BeaconBlockBody` and `ExecutionPayload` are not changed.
+pub use previous_fork::{containers, generalized_index};
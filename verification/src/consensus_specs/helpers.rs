@@ -1,17 +1,66 @@
 //! Helper functions.
 
-use super::phase0;
+use eth2_hashing::hash32_concat;
+use tree_hash::Hash256;
 
-/// See [`compute_epoch_at_slot`].
+/// Hard forks of the beacon chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Fork {
+    Phase0,
+    Altair,
+    Bellatrix,
+    Capella,
+    Deneb,
+    Electra,
+    Fulu,
+}
+
+impl Fork {
+    /// The forks which are scheduled in a chain spec, in order.
+    ///
+    /// [`Fork::Phase0`] isn't in them, since it's activated at the genesis.
+    pub const SCHEDULED: [Self; 6] = [
+        Self::Altair,
+        Self::Bellatrix,
+        Self::Capella,
+        Self::Deneb,
+        Self::Electra,
+        Self::Fulu,
+    ];
+}
+
+/// See [`compute_fork_data_root`].
+///
+/// [`compute_fork_data_root`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#compute_fork_data_root
+pub fn compute_fork_data_root(
+    current_version: [u8; 4],
+    genesis_validators_root: Hash256,
+) -> Hash256 {
+    let mut current_version_chunk = [0u8; 32];
+    current_version_chunk[..4].copy_from_slice(&current_version);
+    let root = hash32_concat(&current_version_chunk, genesis_validators_root.as_bytes());
+    Hash256::from(root)
+}
+
+/// See [`compute_domain`].
 ///
-/// [`compute_epoch_at_slot`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#compute_epoch_at_slot
-pub const fn compute_epoch_at_slot(slot: u64) -> u64 {
-    slot / phase0::SLOTS_PER_EPOCH
+/// [`compute_domain`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#compute_domain
+pub fn compute_domain(
+    domain_type: [u8; 4],
+    fork_version: [u8; 4],
+    genesis_validators_root: Hash256,
+) -> Hash256 {
+    let fork_data_root = compute_fork_data_root(fork_version, genesis_validators_root);
+    let mut domain = [0u8; 32];
+    domain[..4].copy_from_slice(&domain_type);
+    domain[4..].copy_from_slice(&fork_data_root.as_bytes()[..28]);
+    Hash256::from(domain)
 }
 
-/// See [`compute_start_slot_at_epoch`].
+/// See [`compute_signing_root`].
 ///
-/// [`compute_start_slot_at_epoch`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#compute_start_slot_at_epoch
-pub const fn compute_start_slot_at_epoch(epoch: u64) -> u64 {
-    epoch * phase0::SLOTS_PER_EPOCH
+/// [`compute_signing_root`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#compute_signing_root
+pub fn compute_signing_root(object_root: Hash256, domain: Hash256) -> Hash256 {
+    let root = hash32_concat(object_root.as_bytes(), domain.as_bytes());
+    Hash256::from(root)
 }
@@ -1,5 +1,30 @@
 macro_rules! define_generalized_index_mod {
     ($containers:path) => {
+        define_generalized_index_mod!($containers, {});
+    };
+    ($containers:path, with_withdrawals) => {
+        define_generalized_index_mod!($containers, {
+            /// Offset to calculate generalized index for a withdrawal in `Withdrawals`.
+            pub const WITHDRAWAL_IN_WITHDRAWALS_OFFSET: usize =
+                2usize.pow(containers::WITHDRAWALS_DEPTH + 1);
+
+            /// Offset to calculate generalized index for a withdrawal in `BlockBody`.
+            pub const WITHDRAWAL_IN_BLOCK_BODY_OFFSET: usize =
+                withdrawal_in_block_body_offset(containers::WITHDRAWALS_DEPTH);
+
+            /// Offset to calculate generalized index for a withdrawal in `BlockBody`, with the
+            /// depth of withdrawals in the preset.
+            pub const fn withdrawal_in_block_body_offset(withdrawals_depth: u32) -> usize {
+                // The withdrawals are the left child of the `Withdrawals` list root, beside the
+                // length of the list.
+                let withdrawals_root = execution_payload_field_in_block_body(
+                    containers::WITHDRAWALS_IN_EXECUTION_PAYLOAD_INDEX,
+                ) * 2;
+                withdrawals_root << withdrawals_depth
+            }
+        });
+    };
+    ($containers:path, { $($extra:item)* }) => {
         /// Pre-computed parameters for [generalized index].
         ///
         /// [generalized index]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/ssz/merkle-proofs.md#generalized-merkle-tree-index
@@ -47,6 +72,23 @@ macro_rules! define_generalized_index_mod {
                     + SIZE / FIELDS_COUNT_2 / FIELDS_COUNT_1 * FIELD_INDEX_1
             };
 
+            /// Generalized index for `state_root` in `ExecutionPayload`.
+            pub const STATE_ROOT_IN_EXECUTION_PAYLOAD: usize = {
+                const DEPTH: u32 = containers::EXECUTION_PAYLOAD_DEPTH;
+                const SIZE: usize = 2usize.pow(DEPTH);
+
+                const FIELDS_COUNT_1: usize =
+                    containers::EXECUTION_PAYLOAD_FIELDS_COUNT.next_power_of_two();
+                const FIELD_INDEX_1: usize = containers::STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
+
+                SIZE + SIZE / FIELDS_COUNT_1 * FIELD_INDEX_1
+            };
+
+            /// Generalized index for `state_root` in `BlockBody`.
+            pub const STATE_ROOT_IN_BLOCK_BODY: usize = execution_payload_field_in_block_body(
+                containers::STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+            );
+
             /// Generalized index for `receipts_root` in `ExecutionPayload`.
             pub const RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD: usize = {
                 const DEPTH: u32 = containers::EXECUTION_PAYLOAD_DEPTH;
@@ -60,21 +102,27 @@ macro_rules! define_generalized_index_mod {
             };
 
             /// Generalized index for `receipts_root` in `BlockBody`.
-            pub const RECEIPTS_ROOT_IN_BLOCK_BODY: usize = {
+            pub const RECEIPTS_ROOT_IN_BLOCK_BODY: usize = execution_payload_field_in_block_body(
+                containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX,
+            );
+
+            /// Generalized index for a field of `ExecutionPayload` in `BlockBody`.
+            pub const fn execution_payload_field_in_block_body(field_index: usize) -> usize {
                 const DEPTH: u32 =
                     containers::EXECUTION_PAYLOAD_DEPTH + containers::BLOCK_BODY_DEPTH;
                 const SIZE: usize = 2usize.pow(DEPTH);
 
                 const FIELDS_COUNT_1: usize =
                     containers::EXECUTION_PAYLOAD_FIELDS_COUNT.next_power_of_two();
-                const FIELD_INDEX_1: usize = containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
                 const FIELDS_COUNT_2: usize =
                     containers::BLOCK_BODY_FIELDS_COUNT.next_power_of_two();
                 const FIELD_INDEX_2: usize = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
 
                 SIZE + SIZE / FIELDS_COUNT_2 * FIELD_INDEX_2
-                    + SIZE / FIELDS_COUNT_2 / FIELDS_COUNT_1 * FIELD_INDEX_1
-            };
+                    + SIZE / FIELDS_COUNT_2 / FIELDS_COUNT_1 * field_index
+            }
+
+            $($extra)*
         }
     };
 }
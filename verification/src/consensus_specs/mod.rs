@@ -11,5 +11,9 @@ pub mod phase0;
 pub mod altair;
 pub mod bellatrix;
 pub mod capella;
+pub mod deneb;
+pub mod electra;
+pub mod fulu;
 
+pub mod chain_spec;
 pub mod helpers;
@@ -5,4 +5,30 @@
 /// From [The Beacon Chain / Configuration / Time parameters].
 ///
 /// [Configuration/ Time parameters]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#time-parameters
-pub(crate) const SLOTS_PER_EPOCH: u64 = 32;
+pub const SLOTS_PER_EPOCH: u64 = 32;
+
+/// From [The Beacon Chain / Configuration / Time parameters].
+///
+/// [The Beacon Chain / Configuration / Time parameters]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#time-parameters
+pub const SLOTS_PER_HISTORICAL_ROOT: u64 = 8192;
+
+/// From [The Beacon Chain / Configuration / State list lengths].
+///
+/// [The Beacon Chain / Configuration / State list lengths]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#state-list-lengths
+pub const HISTORICAL_ROOTS_LIMIT: usize = 16_777_216;
+
+/// From [The Beacon Chain / Constants].
+///
+/// [The Beacon Chain / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#constants
+pub const FAR_FUTURE_EPOCH: u64 = u64::MAX;
+
+/// From [The Beacon Chain / Configuration / Genesis settings].
+///
+/// [The Beacon Chain / Configuration / Genesis settings]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#genesis-settings
+pub const GENESIS_FORK_VERSION: [u8; 4] = [0x00, 0x00, 0x00, 0x00];
+
+/// The `genesis_validators_root` in the genesis state of the mainnet.
+pub const GENESIS_VALIDATORS_ROOT: [u8; 32] = [
+    0x4b, 0x36, 0x3d, 0xb9, 0x4e, 0x28, 0x61, 0x20, 0xd7, 0x6e, 0xb9, 0x05, 0x34, 0x0f, 0xdd, 0x4e,
+    0x54, 0xbf, 0xe9, 0xf0, 0x6b, 0xf3, 0x3f, 0xf6, 0xcf, 0x5a, 0xd2, 0x7f, 0x51, 0x1b, 0xfe, 0x95,
+];
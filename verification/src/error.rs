@@ -1,12 +1,39 @@
 #[repr(i8)]
 pub enum ProofUpdateError {
     EmptyUpdates = 1,
-    FirstHeaderForCreate,
-    FirstHeaderSlot,
+    // The code 2 was `FirstHeaderForCreate`, it's reserved to keep the codes of the others.
+    FirstHeaderSlot = 3,
     FirstHeaderParentRoot,
     UncontinuousSlot,
     UnmatchedParentRoot,
     HeadersMmrProof,
+    UnorderedSlots,
+    SyncCommitteeParticipation,
+    SyncAggregateSignature,
+    UnattestedTipHeader,
+    FinalityBranch,
+    UnmatchedSyncCommittee,
+    NextSyncCommitteeSszProof,
+    Other = 15,
+    // The error codes before `Other` are all used.
+    TooFewUpdates = 16,
+}
+
+#[repr(i8)]
+pub enum BootstrapError {
+    TrustedBlockRoot = 1,
+    CurrentSyncCommitteeSszProof,
+    Other = 15,
+}
+
+/// Errors of verifying a header in the headers MMR of a client, which are shared by the proofs
+/// of the data in a block.
+#[repr(i8)]
+pub enum HeaderVerificationError {
+    Unsynchronized = 1,
+    HeaderMmrProof,
+    EmptyHeaders,
+    UnorderedHeaders,
     Other = 15,
 }
 
@@ -19,3 +46,100 @@ pub enum TxVerificationError {
     HeaderMmrProof,
     Other = 15,
 }
+
+#[repr(i8)]
+pub enum FieldVerificationError {
+    FieldIndex = 1,
+    FieldSszProof,
+    Unsynchronized,
+    HeaderMmrProof,
+    Other = 15,
+}
+
+#[repr(i8)]
+pub enum StateVerificationError {
+    StateRootSszProof = 1,
+    AccountMptProof,
+    StorageMptProof,
+    Unsynchronized,
+    HeaderMmrProof,
+    Other = 15,
+}
+
+#[repr(i8)]
+pub enum WithdrawalVerificationError {
+    WithdrawalIndex = 1,
+    WithdrawalSszProof,
+    BeforeCapella,
+    Unsynchronized,
+    HeaderMmrProof,
+    Other = 15,
+}
+
+#[repr(i8)]
+pub enum HistoricalBlockVerificationError {
+    AnchorSlot = 1,
+    BeforeCapella,
+    HeaderSszProof,
+    Unsynchronized,
+    HeaderMmrProof,
+    Other = 15,
+}
+
+#[repr(i8)]
+pub enum HeadersWindowError {
+    WindowSize = 1,
+    DigestsCount,
+    HeadersMmrProof,
+    TooFewDroppedSlots,
+    Other = 15,
+}
+
+#[repr(i8)]
+pub enum ClientCellsError {
+    CellsCount = 1,
+    LastId,
+    ReplacedClientId,
+    NewClientId,
+    NewLastId,
+    MinimalUpdatesCount,
+    Other = 15,
+}
+
+#[repr(i8)]
+pub enum ReceiptError {
+    TransactionType = 1,
+    Decode,
+    Status,
+    LogIndex,
+    UnmatchedLogAddress,
+    UnmatchedLogTopic,
+    Other = 15,
+}
+
+macro_rules! impl_from_header_verification_error {
+    ($($error:ident),+ $(,)?) => {
+        $(
+            impl From<HeaderVerificationError> for $error {
+                fn from(err: HeaderVerificationError) -> Self {
+                    match err {
+                        HeaderVerificationError::Unsynchronized => Self::Unsynchronized,
+                        HeaderVerificationError::HeaderMmrProof => Self::HeaderMmrProof,
+                        // Only for several headers, which aren't in these proofs.
+                        HeaderVerificationError::EmptyHeaders
+                        | HeaderVerificationError::UnorderedHeaders
+                        | HeaderVerificationError::Other => Self::Other,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_from_header_verification_error!(
+    TxVerificationError,
+    FieldVerificationError,
+    StateVerificationError,
+    WithdrawalVerificationError,
+    HistoricalBlockVerificationError,
+);
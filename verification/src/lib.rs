@@ -13,7 +13,7 @@ pub mod error;
 pub mod types;
 
 mod utilities;
-pub use utilities::{mmr, ssz, trie};
+pub use utilities::{bls, mmr, receipt, ssz, trie};
 
 #[cfg(test)]
 mod tests;
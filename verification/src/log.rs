@@ -32,15 +32,3 @@ macro_rules! error {
         log::error!($( $args )*);
     }
 }
-
-#[cfg(feature = "std")]
-macro_rules! log_if_enabled {
-    (|$level:tt | $block_expr:block) => {
-        if log::log_enabled!(log::Level::$level) $block_expr
-    };
-}
-
-#[cfg(not(feature = "std"))]
-macro_rules! log_if_enabled {
-    (|$level:tt | $block_expr:block) => {};
-}
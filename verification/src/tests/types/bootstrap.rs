@@ -0,0 +1,177 @@
+use super::{load_beacon_block_header_from_json_or_create_default, MockSyncCommittee};
+use crate::{
+    consensus_specs::helpers::Fork,
+    mmr,
+    tests::{find_json_files, setup},
+    types::{core, packed, prelude::*},
+};
+
+#[test]
+fn new_client_from_bootstrap() {
+    let param = BootstrapParameter::default();
+    new_client(param);
+}
+
+#[test]
+fn new_client_from_bootstrap_after_fork_electra() {
+    let param = BootstrapParameter {
+        after_fork_electra: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+fn new_client_from_bootstrap_on_sepolia() {
+    let param = BootstrapParameter {
+        on_sepolia: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to create client from bootstrap")]
+fn new_client_from_bootstrap_with_untrusted_block_root() {
+    let param = BootstrapParameter {
+        untrusted_block_root: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to create client from bootstrap")]
+fn new_client_from_bootstrap_with_invalid_sync_committee_branch() {
+    let param = BootstrapParameter {
+        invalid_sync_committee_branch: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to create client from bootstrap")]
+fn new_client_from_bootstrap_with_another_sync_committee() {
+    let param = BootstrapParameter {
+        another_committee: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to create client from bootstrap")]
+fn new_client_from_bootstrap_with_another_chain_spec() {
+    let param = BootstrapParameter {
+        on_sepolia: true,
+        another_chain_spec: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[derive(Default)]
+struct BootstrapParameter {
+    untrusted_block_root: bool,
+    invalid_sync_committee_branch: bool,
+    another_committee: bool,
+    after_fork_electra: bool,
+    // The header is in Electra on Sepolia, but it's still in Deneb on the mainnet.
+    on_sepolia: bool,
+    another_chain_spec: bool,
+}
+
+fn new_client(param: BootstrapParameter) {
+    setup();
+
+    let spec = if param.on_sepolia {
+        core::ChainSpec::SEPOLIA
+    } else {
+        core::ChainSpec::MAINNET
+    };
+
+    let header = {
+        let case_dir = "mainnet/case-1/beacon";
+        let header_json_file = find_json_files(case_dir, "block-header-slot-")
+            .into_iter()
+            .next()
+            .unwrap();
+        let header = load_beacon_block_header_from_json_or_create_default(header_json_file);
+        let header = packed::Header::from_ssz_header(&header);
+        if param.after_fork_electra || param.on_sepolia {
+            // The beacon state has a new layout since Electra.
+            let slot = spec.compute_start_slot_at_epoch(spec.fork_config(Fork::Electra).epoch);
+            header.as_builder().slot(slot.pack()).build()
+        } else {
+            header
+        }
+    };
+
+    let sync_committee = MockSyncCommittee::new_with_chain_spec(1, spec);
+    let bootstrap = {
+        let bootstrap = sync_committee.bootstrap(header);
+        let mut current_sync_committee_branch: core::SszProof =
+            bootstrap.current_sync_committee_branch().unpack();
+        if param.invalid_sync_committee_branch {
+            current_sync_committee_branch[0] = core::Hash::repeat_byte(0xff);
+        }
+        let current_sync_committee = if param.another_committee {
+            MockSyncCommittee::new(2).packed()
+        } else {
+            bootstrap.current_sync_committee()
+        };
+        bootstrap
+            .as_builder()
+            .current_sync_committee(current_sync_committee)
+            .current_sync_committee_branch(current_sync_committee_branch.pack())
+            .build()
+    };
+    let header_with_cache = {
+        let header: core::Header = bootstrap.header().unpack();
+        header.calc_cache()
+    };
+    let slot = header_with_cache.inner.slot;
+
+    let trusted_block_root = if param.untrusted_block_root {
+        core::Hash::repeat_byte(0xff)
+    } else {
+        header_with_cache.root
+    };
+
+    let headers_mmr_root = {
+        let store = mmr::lib::util::MemStore::default();
+        let mut mmr = mmr::ClientRootMMR::new(0, &store);
+        mmr.push(header_with_cache.digest()).unwrap();
+        mmr.get_root().unwrap()
+    };
+
+    let expected_client = core::Client {
+        id: 0,
+        minimal_slot: slot,
+        maximal_slot: slot,
+        tip_valid_header_root: header_with_cache.root,
+        headers_mmr_root: headers_mmr_root.unpack(),
+        sync_committee_period: spec.compute_sync_committee_period_at_slot(slot),
+        current_sync_committee_root: sync_committee.root(),
+        next_sync_committee_root: core::Hash::zero(),
+    };
+
+    let expected_packed_client = expected_client.pack();
+
+    let spec = if param.another_chain_spec {
+        core::ChainSpec::MAINNET
+    } else {
+        spec
+    };
+    let result = core::Client::new_from_bootstrap(&spec, trusted_block_root, bootstrap.as_reader());
+    assert!(result.is_ok(), "failed to create client from bootstrap");
+
+    if let Ok(actual_client) = result {
+        let actual_packed_client = actual_client.pack();
+        assert_eq!(
+            actual_packed_client.as_slice(),
+            expected_packed_client.as_slice()
+        );
+    }
+}
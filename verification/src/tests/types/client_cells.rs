@@ -0,0 +1,230 @@
+use alloc::vec::Vec;
+
+use super::MINIMAL_DEVNET;
+use crate::{
+    consensus_specs::{self as specs, helpers::Fork},
+    error::ClientCellsError,
+    types::{core, packed, prelude::*},
+};
+
+fn mock_client(id: u8, maximal_slot: u64) -> core::Client {
+    core::Client {
+        id,
+        minimal_slot: 0,
+        maximal_slot,
+        tip_valid_header_root: core::Hash::repeat_byte(1),
+        headers_mmr_root: core::HeaderDigest {
+            children_hash: core::Hash::repeat_byte(2),
+        },
+        sync_committee_period: 0,
+        current_sync_committee_root: core::Hash::repeat_byte(3),
+        next_sync_committee_root: core::Hash::zero(),
+    }
+}
+
+#[test]
+fn rotate_client_cells() {
+    for cells_count in [1u8, 2, 3, 255] {
+        // All client cells are the same client when they are created.
+        let mut clients = (0..cells_count)
+            .map(|id| mock_client(id, 0))
+            .collect::<Vec<_>>();
+        let mut info = core::ClientInfo {
+            last_id: 0,
+            minimal_updates_count: 1,
+        };
+        for slot in 1..=(u64::from(cells_count) * 2 + 1) {
+            let next_id = info.next_id(cells_count).ok().unwrap();
+            assert_eq!(next_id, (slot % u64::from(cells_count)) as u8);
+
+            let replaced_client = &clients[usize::from(next_id)];
+            let result = info.rotate(cells_count, mock_client(u8::MAX, slot));
+            assert!(result.is_ok());
+            let (new_info, new_client) = result.ok().unwrap();
+            assert_eq!(new_client.id, next_id);
+            assert_eq!(new_info.last_id, next_id);
+            let result = info.verify_rotation(cells_count, replaced_client, &new_info, &new_client);
+            assert!(result.is_ok(), "failed to rotate to client#{next_id}");
+
+            clients[usize::from(next_id)] = new_client;
+            info = new_info;
+
+            let latest_client = info.select_latest_client(&clients);
+            assert_eq!(latest_client.map(|client| client.maximal_slot), Some(slot));
+        }
+    }
+}
+
+#[test]
+fn rotate_client_cells_with_invalid_ids() {
+    let cells_count = 3;
+    let info = core::ClientInfo {
+        last_id: 1,
+        minimal_updates_count: 1,
+    };
+    let replaced_client = mock_client(2, 0);
+    let (new_info, new_client) = info.rotate(cells_count, mock_client(0, 1)).ok().unwrap();
+    assert_eq!(new_client.id, 2);
+
+    let result = info.next_id(0);
+    assert!(matches!(result, Err(ClientCellsError::CellsCount)));
+
+    let result = info.next_id(1);
+    assert!(matches!(result, Err(ClientCellsError::LastId)));
+
+    let result = info.verify_rotation(cells_count, &mock_client(1, 0), &new_info, &new_client);
+    assert!(matches!(result, Err(ClientCellsError::ReplacedClientId)));
+
+    let result = info.verify_rotation(cells_count, &replaced_client, &new_info, &mock_client(1, 1));
+    assert!(matches!(result, Err(ClientCellsError::NewClientId)));
+
+    let mut invalid_info = new_info.clone();
+    invalid_info.last_id = 1;
+    let result = info.verify_rotation(cells_count, &replaced_client, &invalid_info, &new_client);
+    assert!(matches!(result, Err(ClientCellsError::NewLastId)));
+
+    let mut invalid_info = new_info;
+    invalid_info.minimal_updates_count += 1;
+    let result = info.verify_rotation(cells_count, &replaced_client, &invalid_info, &new_client);
+    assert!(matches!(result, Err(ClientCellsError::MinimalUpdatesCount)));
+}
+
+#[test]
+fn change_minimal_updates_count() {
+    let info = core::ClientInfo {
+        last_id: 1,
+        minimal_updates_count: 4,
+    };
+
+    let spec = core::ChainSpec::MAINNET;
+    let slots_per_epoch = spec.preset.slots_per_epoch as u8;
+
+    let mut new_info = info.clone();
+    for minimal_updates_count in [1, 3, 8, slots_per_epoch] {
+        new_info.minimal_updates_count = minimal_updates_count;
+        let result = info.verify_minimal_updates_count_change(&spec, &new_info);
+        assert!(result.is_ok());
+    }
+
+    // Zero, unchanged, or more than the slots in an epoch.
+    for minimal_updates_count in [0, 4, slots_per_epoch + 1, u8::MAX] {
+        new_info.minimal_updates_count = minimal_updates_count;
+        let result = info.verify_minimal_updates_count_change(&spec, &new_info);
+        assert!(matches!(result, Err(ClientCellsError::MinimalUpdatesCount)));
+    }
+
+    new_info.minimal_updates_count = 8;
+    new_info.last_id = 2;
+    let result = info.verify_minimal_updates_count_change(&spec, &new_info);
+    assert!(matches!(result, Err(ClientCellsError::NewLastId)));
+}
+
+#[test]
+fn check_presets() {
+    assert!(core::Preset::MAINNET.is_valid());
+    assert!(core::Preset::MINIMAL.is_valid());
+
+    let invalid_presets = [
+        core::Preset {
+            slots_per_epoch: 0,
+            ..core::Preset::MINIMAL
+        },
+        core::Preset {
+            epochs_per_sync_committee_period: 0,
+            ..core::Preset::MINIMAL
+        },
+        core::Preset {
+            sync_committee_size: 0,
+            ..core::Preset::MINIMAL
+        },
+        core::Preset {
+            max_withdrawals_per_payload: 0,
+            ..core::Preset::MINIMAL
+        },
+        core::Preset {
+            slots_per_historical_root: 0,
+            ..core::Preset::MINIMAL
+        },
+        core::Preset {
+            slots_per_epoch: u64::MAX,
+            ..core::Preset::MINIMAL
+        },
+    ];
+    for preset in invalid_presets {
+        assert!(!preset.is_valid());
+    }
+}
+
+#[test]
+fn check_chain_specs() {
+    for spec in [
+        core::ChainSpec::MAINNET,
+        core::ChainSpec::SEPOLIA,
+        MINIMAL_DEVNET,
+    ] {
+        assert!(spec.is_valid());
+        assert!(spec.pack().as_reader().is_valid());
+    }
+
+    let mut spec = core::ChainSpec::MAINNET;
+    spec.preset.slots_per_epoch = 0;
+    assert!(!spec.is_valid());
+
+    // Deneb is scheduled before Capella.
+    let mut spec = core::ChainSpec::MAINNET;
+    spec.forks.swap(2, 3);
+    assert!(!spec.is_valid());
+
+    // The forks which aren't in the packed chain spec are not scheduled.
+    let packed_spec = core::ChainSpec::MAINNET.pack();
+    let forks = packed::ForkConfigVec::new_builder()
+        .set(packed_spec.forks().into_iter().take(5).collect())
+        .build();
+    let spec: core::ChainSpec = packed_spec
+        .clone()
+        .as_builder()
+        .forks(forks)
+        .build()
+        .unpack();
+    assert!(spec.is_valid());
+    assert_eq!(spec.compute_fork_at_epoch(u64::MAX - 1), Fork::Electra);
+    assert!(spec.fork_config(Fork::Fulu) == core::ForkConfig::UNSCHEDULED);
+
+    // The forks which aren't known can't be verified.
+    let forks = packed_spec
+        .forks()
+        .as_builder()
+        .push(core::ForkConfig::UNSCHEDULED.pack())
+        .build();
+    let packed_spec = packed_spec.as_builder().forks(forks).build();
+    assert!(!packed_spec.as_reader().is_valid());
+}
+
+#[test]
+fn compute_fork_versions() {
+    let spec = core::ChainSpec::MAINNET;
+    let forks = [
+        (0, Fork::Phase0),
+        (specs::altair::FORK_EPOCH, Fork::Altair),
+        (specs::bellatrix::FORK_EPOCH, Fork::Bellatrix),
+        (specs::capella::FORK_EPOCH, Fork::Capella),
+        (specs::deneb::FORK_EPOCH, Fork::Deneb),
+        (specs::electra::FORK_EPOCH, Fork::Electra),
+        (specs::fulu::FORK_EPOCH, Fork::Fulu),
+    ];
+    for (epoch, fork) in forks {
+        assert_eq!(spec.compute_fork_at_epoch(epoch), fork);
+        assert_eq!(spec.fork_config(fork).epoch, epoch);
+        if epoch > 0 {
+            assert!(spec.compute_fork_at_epoch(epoch - 1) < fork);
+        }
+    }
+    assert_eq!(
+        spec.compute_fork_version(specs::fulu::FORK_EPOCH),
+        specs::fulu::FORK_VERSION
+    );
+    assert_eq!(
+        spec.compute_fork_version(specs::fulu::FORK_EPOCH - 1),
+        specs::electra::FORK_VERSION
+    );
+}
@@ -0,0 +1,156 @@
+use alloc::{format, vec::Vec};
+
+use eth2_types::BeaconBlockHeader;
+use eth_light_client_in_ckb_prover::build_headers_proof;
+
+use super::{
+    bootstrap_builder, build_attested_proof_update, convert,
+    load_beacon_block_header_from_json_or_create_default, MockSyncCommittee,
+};
+use crate::{
+    error::HeaderVerificationError,
+    tests::{find_json_files, setup},
+    types::{core, packed, prelude::*},
+};
+
+#[test]
+fn test_headers_verification_case_1() {
+    test_headers_verification(1);
+}
+
+#[test]
+fn test_headers_verification_case_3() {
+    test_headers_verification(3);
+}
+
+fn test_headers_verification(case_id: usize) {
+    setup();
+
+    let beacon_dir = format!("mainnet/case-{}/beacon", case_id);
+    let mut headers = find_json_files(&beacon_dir, "block-header-slot-")
+        .into_iter()
+        .map(load_beacon_block_header_from_json_or_create_default)
+        .collect::<Vec<BeaconBlockHeader>>();
+
+    let sync_committee = MockSyncCommittee::new(0);
+    let (mut builder, client) = bootstrap_builder(&sync_committee, &mut headers);
+    let packed_proof_update =
+        build_attested_proof_update(&mut builder, &sync_committee, &headers[1..]);
+    let client = client
+        .try_apply_packed_proof_update(&core::ChainSpec::MAINNET, packed_proof_update.as_reader())
+        .unwrap_or_else(|_| panic!("failed to apply proof update"));
+
+    // Headers which are far apart in slots, and unordered.
+    let selected = {
+        let last = headers.len() - 1;
+        [last, 0, last / 2, 1, last / 3]
+            .into_iter()
+            .map(|index| headers[index].clone())
+            .collect::<Vec<_>>()
+    };
+    let result = build_headers_proof(builder.client(), &builder.headers_mmr(), &selected);
+    assert!(result.is_ok(), "failed to build proof for headers");
+    let (packed_headers, packed_proof) = result.ok().unwrap();
+    let packed_headers: packed::HeaderVec = convert(packed_headers);
+    let packed_proof: packed::MmrProof = convert(packed_proof);
+    assert_eq!(packed_headers.len(), selected.len());
+
+    let result = client.verify_packed_headers(packed_headers.as_reader(), packed_proof.as_reader());
+    assert!(result.is_ok(), "failed to verify headers");
+
+    let mut unpacked_headers: core::HeaderVec = packed_headers.unpack();
+
+    // Check each header as a reader.
+    for header in &selected {
+        let header_slot = u64::from(header.slot);
+        let depth = client.header_depth(header_slot);
+        assert_eq!(depth, Some(client.maximal_slot - header_slot));
+
+        let result = build_headers_proof(
+            builder.client(),
+            &builder.headers_mmr(),
+            ::core::slice::from_ref(header),
+        );
+        assert!(
+            result.is_ok(),
+            "failed to build proof for header#{header_slot}"
+        );
+        let (packed_headers, packed_proof) = result.ok().unwrap();
+        let packed_headers: packed::HeaderVec = convert(packed_headers);
+        let packed_proof: packed::MmrProof = convert(packed_proof);
+        let packed_header = packed_headers.get(0).unwrap();
+
+        let result = client.contains_header(packed_header.as_reader(), packed_proof.as_reader());
+        assert!(
+            matches!(result, Ok(true)),
+            "header#{header_slot} isn't contained"
+        );
+
+        let mut tampered_header: core::Header = packed_header.unpack();
+        tampered_header.proposer_index += 1;
+        let result =
+            client.contains_header(tampered_header.pack().as_reader(), packed_proof.as_reader());
+        assert!(matches!(result, Ok(false)));
+    }
+    assert_eq!(client.header_depth(client.minimal_slot - 1), None);
+    assert_eq!(client.header_depth(client.maximal_slot + 1), None);
+    {
+        let mut header: core::Header = packed_headers.get(0).unwrap().unpack();
+        header.slot = client.maximal_slot + 1;
+        let result = client.contains_header(header.pack().as_reader(), packed_proof.as_reader());
+        assert!(matches!(result, Ok(false)));
+    }
+
+    // Unordered headers.
+    {
+        let mut headers = unpacked_headers.clone();
+        headers.swap(0, 1);
+        let result =
+            client.verify_packed_headers(headers.pack().as_reader(), packed_proof.as_reader());
+        assert!(matches!(
+            result,
+            Err(HeaderVerificationError::UnorderedHeaders)
+        ));
+    }
+
+    // Headers out of the client.
+    {
+        let mut headers = unpacked_headers.clone();
+        let mut header = headers[headers.len() - 1].clone();
+        header.slot = client.maximal_slot + 1;
+        headers.push(header);
+        let result =
+            client.verify_packed_headers(headers.pack().as_reader(), packed_proof.as_reader());
+        assert!(matches!(
+            result,
+            Err(HeaderVerificationError::Unsynchronized)
+        ));
+    }
+
+    // No headers.
+    {
+        let headers = core::HeaderVec::new();
+        let result =
+            client.verify_packed_headers(headers.pack().as_reader(), packed_proof.as_reader());
+        assert!(matches!(result, Err(HeaderVerificationError::EmptyHeaders)));
+        let result = build_headers_proof(builder.client(), &builder.headers_mmr(), &[]);
+        // The error is from the instance of this crate in the prover.
+        assert_eq!(
+            result.err().map(|err| err as i8),
+            Some(HeaderVerificationError::EmptyHeaders as i8)
+        );
+    }
+
+    // Tampered header.
+    {
+        unpacked_headers[1].proposer_index += 1;
+        let result = client.verify_packed_headers(
+            unpacked_headers.pack().as_reader(),
+            packed_proof.as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(HeaderVerificationError::HeaderMmrProof)
+        ));
+    }
+}
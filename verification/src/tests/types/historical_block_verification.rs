@@ -0,0 +1,359 @@
+use alloc::vec::Vec;
+
+use eth2_hashing::hash32_concat;
+use merkle_proof::MerkleTree;
+use tree_hash::TreeHash as _;
+
+use crate::{
+    consensus_specs::{self as specs, helpers::Fork},
+    error::HistoricalBlockVerificationError,
+    ssz,
+    types::{core, prelude::*},
+};
+
+// There are 28 fields in `BeaconState` since Capella, 37 fields since Electra, and 38 fields since
+// Fulu.
+const BEACON_STATE_FIELDS_COUNT: usize = 28;
+const BEACON_STATE_DEPTH: usize = 5;
+const ELECTRA_BEACON_STATE_FIELDS_COUNT: usize = 37;
+const ELECTRA_BEACON_STATE_DEPTH: usize = 6;
+const FULU_BEACON_STATE_FIELDS_COUNT: usize = 38;
+const FULU_BEACON_STATE_DEPTH: usize = 6;
+
+fn mock_header(slot: u64) -> core::Header {
+    core::Header {
+        slot,
+        proposer_index: 7,
+        parent_root: core::Hash::repeat_byte(1),
+        state_root: core::Hash::repeat_byte(2),
+        body_root: core::Hash::repeat_byte(3),
+    }
+}
+
+// A mocked anchor header, whose state contains the root of the historical header, returns a
+// client which only has the anchor header, and the proof for the historical header.
+fn mock_historical_block_proof(
+    spec: &core::ChainSpec,
+    anchor_slot: u64,
+    header_slot: u64,
+) -> (core::Client, core::HistoricalBlockProof) {
+    let slots_per_historical_root = spec.preset.slots_per_historical_root;
+    let block_roots_depth = spec.preset.block_roots_depth() as usize;
+
+    let header = mock_header(header_slot);
+    let header_in_block_roots = (header_slot % slots_per_historical_root) as usize;
+    let block_roots = {
+        let leaves = (0..slots_per_historical_root as usize)
+            .map(|index| {
+                if index == header_in_block_roots {
+                    header.tree_hash_root()
+                } else {
+                    core::Hash::repeat_byte(index as u8)
+                }
+            })
+            .collect::<Vec<_>>();
+        MerkleTree::create(&leaves, block_roots_depth)
+    };
+    let (_, mut header_ssz_proof) = block_roots
+        .generate_proof(header_in_block_roots, block_roots_depth)
+        .unwrap();
+
+    let (fields_count, depth, block_roots_index, historical_summaries_index) =
+        match spec.compute_fork_at_slot(anchor_slot) {
+            Fork::Fulu => (
+                FULU_BEACON_STATE_FIELDS_COUNT,
+                FULU_BEACON_STATE_DEPTH,
+                specs::fulu::BLOCK_ROOTS_INDEX,
+                specs::fulu::HISTORICAL_SUMMARIES_INDEX,
+            ),
+            Fork::Electra => (
+                ELECTRA_BEACON_STATE_FIELDS_COUNT,
+                ELECTRA_BEACON_STATE_DEPTH,
+                specs::electra::BLOCK_ROOTS_INDEX,
+                specs::electra::HISTORICAL_SUMMARIES_INDEX,
+            ),
+            _ => (
+                BEACON_STATE_FIELDS_COUNT,
+                BEACON_STATE_DEPTH,
+                specs::capella::BLOCK_ROOTS_INDEX,
+                specs::capella::HISTORICAL_SUMMARIES_INDEX,
+            ),
+        };
+    let (field_index, field_root) = if anchor_slot <= header_slot + slots_per_historical_root {
+        (block_roots_index, block_roots.hash())
+    } else {
+        let state_summary_root = core::Hash::repeat_byte(0xaa);
+        let summary_root = core::Hash::from(hash32_concat(
+            block_roots.hash().as_bytes(),
+            state_summary_root.as_bytes(),
+        ));
+        header_ssz_proof.push(state_summary_root);
+
+        let capella_start_slot =
+            spec.compute_start_slot_at_epoch(spec.fork_config(Fork::Capella).epoch);
+        let summary_index = (header_slot / slots_per_historical_root
+            - capella_start_slot / slots_per_historical_root) as usize;
+        let summaries_depth = ssz::ceil_depth(specs::phase0::HISTORICAL_ROOTS_LIMIT) as usize;
+        let summaries_data = {
+            let leaves = (0..=summary_index)
+                .map(|index| {
+                    if index == summary_index {
+                        summary_root
+                    } else {
+                        core::Hash::repeat_byte(0x40 | index as u8)
+                    }
+                })
+                .collect::<Vec<_>>();
+            MerkleTree::create(&leaves, summaries_depth)
+        };
+        let (_, summaries_proof) = summaries_data
+            .generate_proof(summary_index, summaries_depth)
+            .unwrap();
+        header_ssz_proof.extend(summaries_proof);
+        let length_hash = ssz::length_hash(summary_index + 1);
+        header_ssz_proof.push(length_hash);
+        let summaries_root = core::Hash::from(hash32_concat(
+            summaries_data.hash().as_bytes(),
+            length_hash.as_bytes(),
+        ));
+        (historical_summaries_index, summaries_root)
+    };
+
+    let state = {
+        let leaf_index = field_index - (1 << depth);
+        let leaves = (0..fields_count)
+            .map(|index| {
+                if index == leaf_index {
+                    field_root
+                } else {
+                    core::Hash::repeat_byte(0x80 | index as u8)
+                }
+            })
+            .collect::<Vec<_>>();
+        let state = MerkleTree::create(&leaves, depth);
+        let (_, state_proof) = state.generate_proof(leaf_index, depth).unwrap();
+        header_ssz_proof.extend(state_proof);
+        state
+    };
+
+    let anchor_header = core::Header {
+        slot: anchor_slot,
+        proposer_index: 1,
+        parent_root: core::Hash::repeat_byte(4),
+        state_root: state.hash(),
+        body_root: core::Hash::repeat_byte(5),
+    };
+    let client = super::single_header_client(spec, &anchor_header);
+
+    let historical_block_proof = core::HistoricalBlockProof {
+        anchor_header,
+        header,
+        anchor_header_mmr_proof: Vec::new(),
+        header_ssz_proof,
+    };
+    (client, historical_block_proof)
+}
+
+fn verify_historical_block_proofs(spec: &core::ChainSpec, slots_pairs: &[(u64, u64)]) {
+    for (anchor_slot, header_slot) in slots_pairs {
+        let (client, historical_block_proof) =
+            mock_historical_block_proof(spec, *anchor_slot, *header_slot);
+        let result = client
+            .verify_packed_historical_block_proof(spec, historical_block_proof.pack().as_reader());
+        assert!(
+            result.is_ok(),
+            "failed to verify the proof for header#{header_slot} with anchor header#{anchor_slot}"
+        );
+    }
+}
+
+#[test]
+fn verify_historical_block_proofs_in_block_roots() {
+    let spec = core::ChainSpec::MAINNET;
+    let deneb_start_slot = spec.compute_start_slot_at_epoch(specs::deneb::FORK_EPOCH);
+    let electra_start_slot = spec.compute_start_slot_at_epoch(specs::electra::FORK_EPOCH);
+    let slots_pairs = [
+        (deneb_start_slot + 1000, deneb_start_slot + 999),
+        (deneb_start_slot + 10000, deneb_start_slot + 10000 - 8192),
+        (electra_start_slot + 100, electra_start_slot - 100),
+    ];
+    verify_historical_block_proofs(&spec, &slots_pairs);
+}
+
+#[test]
+fn verify_historical_block_proofs_in_historical_summaries() {
+    let spec = core::ChainSpec::MAINNET;
+    let capella_start_slot = spec.compute_start_slot_at_epoch(specs::capella::FORK_EPOCH);
+    let deneb_start_slot = spec.compute_start_slot_at_epoch(specs::deneb::FORK_EPOCH);
+    let electra_start_slot = spec.compute_start_slot_at_epoch(specs::electra::FORK_EPOCH);
+    let slots_pairs = [
+        (deneb_start_slot + 10000, deneb_start_slot + 10000 - 8193),
+        (deneb_start_slot, capella_start_slot),
+        (
+            electra_start_slot + 100,
+            capella_start_slot + 3 * 8192 + 100,
+        ),
+    ];
+    verify_historical_block_proofs(&spec, &slots_pairs);
+}
+
+#[test]
+fn verify_historical_block_proofs_with_minimal_preset() {
+    let spec = super::MINIMAL_DEVNET;
+    let slots_pairs = [
+        (1000, 999),
+        (1000, 1000 - 64),
+        (1000, 1000 - 65),
+        (1000, 10),
+    ];
+    verify_historical_block_proofs(&spec, &slots_pairs);
+}
+
+#[test]
+fn verify_historical_block_proofs_with_unaligned_fork_capella() {
+    // The Capella fork is at slot 72, which isn't aligned to the 64 slots of the block roots.
+    let mut spec = super::MINIMAL_DEVNET;
+    for (fork, config) in Fork::SCHEDULED.iter().zip(spec.forks.iter_mut()) {
+        if *fork == Fork::Capella {
+            config.epoch = 9;
+        } else if *fork > Fork::Capella {
+            config.epoch = specs::phase0::FAR_FUTURE_EPOCH;
+        }
+    }
+    // The slots [64, 128) are in the first historical summary, even the ones before the fork.
+    let slots_pairs = [
+        (1000, 64),
+        (1000, 70),
+        (1000, 72),
+        (1000, 130),
+        (1000, 1000 - 65),
+    ];
+    verify_historical_block_proofs(&spec, &slots_pairs);
+
+    let (client, mut historical_block_proof) = mock_historical_block_proof(&spec, 1000, 64);
+    historical_block_proof.header.slot = 63;
+    let result = client
+        .verify_packed_historical_block_proof(&spec, historical_block_proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::BeforeCapella)
+    ));
+}
+
+#[test]
+fn verify_historical_block_proofs_without_fork_capella() {
+    let mut spec = super::MINIMAL_DEVNET;
+    for (fork, config) in Fork::SCHEDULED.iter().zip(spec.forks.iter_mut()) {
+        if *fork >= Fork::Capella {
+            *config = core::ForkConfig::UNSCHEDULED;
+        }
+    }
+    // The headers in the block roots are still verifiable.
+    verify_historical_block_proofs(&spec, &[(1000, 999), (1000, 1000 - 64)]);
+
+    // The start slot of the Capella fork overflows.
+    let (client, mut historical_block_proof) = mock_historical_block_proof(&spec, 1000, 999);
+    historical_block_proof.header.slot = 10;
+    let result = client
+        .verify_packed_historical_block_proof(&spec, historical_block_proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::BeforeCapella)
+    ));
+}
+
+#[test]
+fn verify_ancestor_headers() {
+    let spec = core::ChainSpec::MAINNET;
+    let electra_start_slot = spec.compute_start_slot_at_epoch(specs::electra::FORK_EPOCH);
+    let anchor_slot = electra_start_slot + 100;
+
+    for header_slot in [anchor_slot - 1, anchor_slot - 8192] {
+        let (client, historical_block_proof) =
+            mock_historical_block_proof(&spec, anchor_slot, header_slot);
+        let result =
+            client.verify_ancestor_header(&spec, historical_block_proof.pack().as_reader());
+        assert!(
+            result.is_ok(),
+            "failed to verify ancestor header#{header_slot} with anchor header#{anchor_slot}"
+        );
+    }
+
+    let (client, historical_block_proof) =
+        mock_historical_block_proof(&spec, anchor_slot, anchor_slot - 8193);
+    let result = client
+        .verify_packed_historical_block_proof(&spec, historical_block_proof.pack().as_reader());
+    assert!(result.is_ok());
+    let result = client.verify_ancestor_header(&spec, historical_block_proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::AnchorSlot)
+    ));
+
+    // The end of the block roots overflows.
+    let (client, mut historical_block_proof) =
+        mock_historical_block_proof(&spec, anchor_slot, anchor_slot - 1);
+    historical_block_proof.header.slot = u64::MAX;
+    let result = client.verify_ancestor_header(&spec, historical_block_proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::AnchorSlot)
+    ));
+}
+
+#[test]
+fn verify_invalid_historical_block_proofs() {
+    let spec = core::ChainSpec::MAINNET;
+    let capella_start_slot = spec.compute_start_slot_at_epoch(specs::capella::FORK_EPOCH);
+    let electra_start_slot = spec.compute_start_slot_at_epoch(specs::electra::FORK_EPOCH);
+    let (client, historical_block_proof) =
+        mock_historical_block_proof(&spec, electra_start_slot + 100, capella_start_slot + 100);
+
+    let mut proof = historical_block_proof.clone();
+    proof.header.proposer_index += 1;
+    let result = client.verify_packed_historical_block_proof(&spec, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::HeaderSszProof)
+    ));
+
+    let mut proof = historical_block_proof.clone();
+    proof.header.slot += 8192;
+    let result = client.verify_packed_historical_block_proof(&spec, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::HeaderSszProof)
+    ));
+
+    let mut proof = historical_block_proof.clone();
+    proof.header.slot = proof.anchor_header.slot;
+    let result = client.verify_packed_historical_block_proof(&spec, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::AnchorSlot)
+    ));
+
+    let mut proof = historical_block_proof.clone();
+    proof.header.slot = capella_start_slot - 1;
+    let result = client.verify_packed_historical_block_proof(&spec, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::BeforeCapella)
+    ));
+
+    let mut proof = historical_block_proof.clone();
+    proof.anchor_header.slot += 1;
+    let result = client.verify_packed_historical_block_proof(&spec, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::Unsynchronized)
+    ));
+
+    let mut proof = historical_block_proof;
+    proof.anchor_header.proposer_index += 1;
+    let result = client.verify_packed_historical_block_proof(&spec, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(HistoricalBlockVerificationError::HeaderMmrProof)
+    ));
+}
@@ -1,9 +1,30 @@
+use ::core::iter;
+use alloc::{vec, vec::Vec};
 use std::{fs::read_to_string, path::PathBuf};
 
+use blst::min_pk::{AggregatePublicKey, AggregateSignature, SecretKey};
+use eth2_hashing::hash32_concat;
 use eth2_types::BeaconBlockHeader;
+use eth_light_client_in_ckb_prover::ProofUpdateBuilder;
+use merkle_proof::MerkleTree;
+use tree_hash::TreeHash as _;
 
+use crate::{
+    bls,
+    consensus_specs::{self as specs, helpers::Fork},
+    types::{core, packed, prelude::*},
+};
+
+mod bootstrap;
+mod client_cells;
+mod headers_verification;
+mod historical_block_verification;
+mod payload_field_verification;
 mod proof_update;
+mod proof_update_builder;
+mod state_verification;
 mod transaction_verification;
+mod withdrawal_verification;
 
 pub(crate) fn load_beacon_block_header_from_json_or_create_default(
     file: PathBuf,
@@ -27,3 +48,404 @@ pub(crate) fn load_beacon_block_header_from_json_or_create_default(
         serde_json::from_value(json_value["data"]["header"]["message"].clone()).unwrap()
     }
 }
+
+// Converts the packed types between the verification crate which is tested and the one which is
+// used by the prover crate.
+pub(crate) fn convert<T: Entity, U: Entity>(entity: T) -> U {
+    U::new_unchecked(entity.as_bytes())
+}
+
+// A client which only has one header, so the MMR proof for the header is empty.
+pub(crate) fn single_header_client(spec: &core::ChainSpec, header: &core::Header) -> core::Client {
+    let header_with_cache = header.clone().calc_cache();
+    core::Client {
+        id: 0,
+        minimal_slot: header.slot,
+        maximal_slot: header.slot,
+        tip_valid_header_root: header_with_cache.root,
+        headers_mmr_root: header_with_cache.digest().unpack(),
+        sync_committee_period: spec.compute_sync_committee_period_at_slot(header.slot),
+        current_sync_committee_root: core::Hash::zero(),
+        next_sync_committee_root: core::Hash::zero(),
+    }
+}
+
+// Creates a client from a bootstrap of the first header, and a builder to build proof updates for
+// the other headers, which are re-linked to the bootstrap.
+//
+// The client is the one in the crate which is tested, and it's same as the client in the builder.
+// The builder follows the mainnet, so should the sync committee.
+pub(crate) fn bootstrap_builder(
+    sync_committee: &MockSyncCommittee,
+    headers: &mut [BeaconBlockHeader],
+) -> (ProofUpdateBuilder, core::Client) {
+    let bootstrap = sync_committee.bootstrap_headers(headers);
+    let trusted_block_root = {
+        let header: core::Header = bootstrap.header().unpack();
+        header.calc_cache().root
+    };
+    let client = core::Client::new_from_bootstrap(
+        sync_committee.spec(),
+        trusted_block_root,
+        bootstrap.as_reader(),
+    )
+    .unwrap_or_else(|_| panic!("failed to create client from bootstrap"));
+    let builder = ProofUpdateBuilder::with_bootstrap(
+        Default::default(),
+        trusted_block_root,
+        convert(bootstrap),
+        Default::default(),
+    )
+    .unwrap_or_else(|_| panic!("failed to create builder from bootstrap"));
+    (builder, client)
+}
+
+// Attests the tip valid header, and provides the next sync committee if it's in the same period.
+pub(crate) fn attest(
+    sync_committee: &MockSyncCommittee,
+    tip_valid_header: packed::Header,
+) -> (packed::FinalityUpdate, Option<(core::Hash, core::SszProof)>) {
+    let spec = sync_committee.spec();
+    let tip_update = sync_committee.attest(tip_valid_header.clone(), sync_committee.size());
+    let tip_update_period = {
+        let attested_header: core::Header = tip_update.attested_header().unpack();
+        spec.compute_sync_committee_period_at_slot(attested_header.slot)
+    };
+    let tip_valid_header_period =
+        spec.compute_sync_committee_period_at_slot(tip_valid_header.slot().unpack());
+    let next_sync_committee_opt = if tip_update_period == tip_valid_header_period {
+        let ssz_proof = sync_committee.next_sync_committee_branch(tip_valid_header);
+        Some((sync_committee.root(), ssz_proof))
+    } else {
+        None
+    };
+    (tip_update, next_sync_committee_opt)
+}
+
+// Builds a proof update for the headers, and its tip valid header is attested by the sync
+// committee.
+pub(crate) fn build_attested_proof_update(
+    builder: &mut ProofUpdateBuilder,
+    sync_committee: &MockSyncCommittee,
+    headers: &[BeaconBlockHeader],
+) -> packed::ProofUpdate {
+    for header in headers {
+        builder.push_header(header);
+    }
+    let tip_valid_header = builder.tip_valid_header().cloned().unwrap();
+    let (tip_update, next_sync_committee_opt) = attest(sync_committee, convert(tip_valid_header));
+    builder.set_tip_update(convert(tip_update));
+    if let Some((root, ssz_proof)) = next_sync_committee_opt {
+        builder.set_next_sync_committee(root, ssz_proof);
+    }
+    convert(
+        builder
+            .build()
+            .unwrap_or_else(|_| panic!("failed to build proof update")),
+    )
+}
+
+// A local devnet which uses the minimal preset, and all forks are enabled since the genesis.
+//
+// The fork versions are the ones in the minimal config of the consensus specs.
+pub(crate) const MINIMAL_DEVNET: core::ChainSpec = core::ChainSpec {
+    genesis_validators_root: tree_hash::Hash256([0x42; 32]),
+    genesis_fork_version: [0x00, 0x00, 0x00, 0x01],
+    preset: core::Preset::MINIMAL,
+    forks: [
+        core::ForkConfig {
+            version: [0x01, 0x00, 0x00, 0x01],
+            epoch: 0,
+        },
+        core::ForkConfig {
+            version: [0x02, 0x00, 0x00, 0x01],
+            epoch: 0,
+        },
+        core::ForkConfig {
+            version: [0x03, 0x00, 0x00, 0x01],
+            epoch: 0,
+        },
+        core::ForkConfig {
+            version: [0x04, 0x00, 0x00, 0x01],
+            epoch: 0,
+        },
+        core::ForkConfig {
+            version: [0x05, 0x00, 0x00, 0x01],
+            epoch: 0,
+        },
+        core::ForkConfig {
+            version: [0x06, 0x00, 0x00, 0x01],
+            epoch: 0,
+        },
+    ],
+};
+
+// There are 24 fields in `BeaconState` of Altair, later forks have more fields in the same depth
+// until Electra, which has 37 fields, and Fulu has 38 fields.
+const BEACON_STATE_FIELDS_COUNT: usize = 24;
+const BEACON_STATE_DEPTH: usize = 5;
+const ELECTRA_BEACON_STATE_FIELDS_COUNT: usize = 37;
+const ELECTRA_BEACON_STATE_DEPTH: usize = 6;
+const FULU_BEACON_STATE_FIELDS_COUNT: usize = 38;
+const FULU_BEACON_STATE_DEPTH: usize = 6;
+
+// The layout of `BeaconState` at the slot.
+struct BeaconStateLayout {
+    fields_count: usize,
+    depth: usize,
+    finalized_root_index: usize,
+    current_sync_committee_index: usize,
+    next_sync_committee_index: usize,
+}
+
+impl BeaconStateLayout {
+    fn at_slot(spec: &core::ChainSpec, slot: u64) -> Self {
+        match spec.compute_fork_at_slot(slot) {
+            Fork::Fulu => Self {
+                fields_count: FULU_BEACON_STATE_FIELDS_COUNT,
+                depth: FULU_BEACON_STATE_DEPTH,
+                finalized_root_index: specs::fulu::FINALIZED_ROOT_INDEX,
+                current_sync_committee_index: specs::fulu::CURRENT_SYNC_COMMITTEE_INDEX,
+                next_sync_committee_index: specs::fulu::NEXT_SYNC_COMMITTEE_INDEX,
+            },
+            Fork::Electra => Self {
+                fields_count: ELECTRA_BEACON_STATE_FIELDS_COUNT,
+                depth: ELECTRA_BEACON_STATE_DEPTH,
+                finalized_root_index: specs::electra::FINALIZED_ROOT_INDEX,
+                current_sync_committee_index: specs::electra::CURRENT_SYNC_COMMITTEE_INDEX,
+                next_sync_committee_index: specs::electra::NEXT_SYNC_COMMITTEE_INDEX,
+            },
+            _ => Self {
+                fields_count: BEACON_STATE_FIELDS_COUNT,
+                depth: BEACON_STATE_DEPTH,
+                finalized_root_index: specs::altair::FINALIZED_ROOT_INDEX,
+                current_sync_committee_index: specs::altair::CURRENT_SYNC_COMMITTEE_INDEX,
+                next_sync_committee_index: specs::altair::NEXT_SYNC_COMMITTEE_INDEX,
+            },
+        }
+    }
+
+    // The index of the leaf in the tree of the state.
+    fn leaf_index(&self, generalized_index: usize) -> usize {
+        generalized_index - (1 << self.depth)
+    }
+}
+
+/// A sync committee with known secret keys, to sign attested headers in tests.
+pub(crate) struct MockSyncCommittee {
+    secret_keys: Vec<SecretKey>,
+    sync_committee: core::SyncCommittee,
+    spec: core::ChainSpec,
+}
+
+impl MockSyncCommittee {
+    pub(crate) fn new(seed: u8) -> Self {
+        Self::new_with_chain_spec(seed, core::ChainSpec::MAINNET)
+    }
+
+    /// Creates a sync committee which signs for the network of the chain spec, and its size is
+    /// defined by the preset of the chain spec.
+    pub(crate) fn new_with_chain_spec(seed: u8, spec: core::ChainSpec) -> Self {
+        let secret_keys = (0..spec.preset.sync_committee_size)
+            .map(|index| {
+                let mut ikm = [seed; 32];
+                ikm[..8].copy_from_slice(&index.to_le_bytes());
+                SecretKey::key_gen(&ikm, &[]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let pubkeys = secret_keys
+            .iter()
+            .map(|secret_key| secret_key.sk_to_pk())
+            .collect::<Vec<_>>();
+        let aggregate_pubkey = {
+            let pubkeys_refs = pubkeys.iter().collect::<Vec<_>>();
+            AggregatePublicKey::aggregate(&pubkeys_refs, false)
+                .unwrap()
+                .to_public_key()
+        };
+        let sync_committee = core::SyncCommittee {
+            pubkeys: pubkeys.iter().map(|pubkey| pubkey.compress()).collect(),
+            aggregate_pubkey: aggregate_pubkey.compress(),
+        };
+        Self {
+            secret_keys,
+            sync_committee,
+            spec,
+        }
+    }
+
+    pub(crate) fn spec(&self) -> &core::ChainSpec {
+        &self.spec
+    }
+
+    /// The count of all members of the sync committee.
+    pub(crate) fn size(&self) -> usize {
+        self.secret_keys.len()
+    }
+
+    pub(crate) fn packed(&self) -> packed::SyncCommittee {
+        self.sync_committee.pack()
+    }
+
+    pub(crate) fn root(&self) -> core::Hash {
+        self.packed().as_reader().calc_root(&self.spec)
+    }
+
+    /// Creates the proof of the next sync committee, in the attested state of the finality
+    /// update for the finalized header.
+    ///
+    /// The next sync committee is the same as the current sync committee.
+    pub(crate) fn next_sync_committee_branch(
+        &self,
+        finalized_header: packed::Header,
+    ) -> core::SszProof {
+        let finalized_header: core::Header = finalized_header.unpack();
+        let layout = BeaconStateLayout::at_slot(&self.spec, self.attested_slot(&finalized_header));
+        let attested_state = self.attested_state(&layout, &finalized_header);
+        let index = layout.leaf_index(layout.next_sync_committee_index);
+        let (_, proof) = attested_state.generate_proof(index, layout.depth).unwrap();
+        proof
+    }
+
+    /// Creates a bootstrap from the header, with its state root replaced by a mocked state which
+    /// contains the sync committee.
+    pub(crate) fn bootstrap(&self, header: packed::Header) -> packed::LightClientBootstrap {
+        let mut header: core::Header = header.unpack();
+        let layout = BeaconStateLayout::at_slot(&self.spec, header.slot);
+        let state = self.attested_state(&layout, &header);
+        header.state_root = state.hash();
+        let current_sync_committee_branch = {
+            let index = layout.leaf_index(layout.current_sync_committee_index);
+            let (_, proof) = state.generate_proof(index, layout.depth).unwrap();
+            proof
+        };
+        core::LightClientBootstrap {
+            header,
+            current_sync_committee: self.sync_committee.clone(),
+            current_sync_committee_branch,
+        }
+        .pack()
+    }
+
+    /// Creates a bootstrap from the first header, and re-links the following non-empty headers to
+    /// it, since the state root of the first header is replaced.
+    pub(crate) fn bootstrap_headers(
+        &self,
+        headers: &mut [BeaconBlockHeader],
+    ) -> packed::LightClientBootstrap {
+        let bootstrap = self.bootstrap(packed::Header::from_ssz_header(&headers[0]));
+        let bootstrap_header: core::Header = bootstrap.header().unpack();
+        headers[0].state_root = bootstrap_header.state_root;
+        let mut parent_root = bootstrap_header.calc_cache().root;
+        for header in headers.iter_mut().skip(1) {
+            let unpacked: core::Header = packed::Header::from_ssz_header(header).unpack();
+            if unpacked.is_empty() {
+                continue;
+            }
+            header.parent_root = parent_root;
+            let unpacked: core::Header = packed::Header::from_ssz_header(header).unpack();
+            parent_root = unpacked.calc_cache().root;
+        }
+        bootstrap
+    }
+
+    // The slot of the attested header for the finalized header.
+    fn attested_slot(&self, finalized_header: &core::Header) -> u64 {
+        finalized_header.slot + self.spec.preset.slots_per_epoch * 2
+    }
+
+    // A mocked beacon state, only the finalized checkpoint and the sync committees are meaningful.
+    fn attested_state(
+        &self,
+        layout: &BeaconStateLayout,
+        finalized_header: &core::Header,
+    ) -> MerkleTree {
+        let finalized_checkpoint_index = layout.leaf_index(layout.finalized_root_index >> 1);
+        let current_sync_committee_index = layout.leaf_index(layout.current_sync_committee_index);
+        let next_sync_committee_index = layout.leaf_index(layout.next_sync_committee_index);
+        let sync_committee_root = self.root();
+        let leaves = (0..layout.fields_count)
+            .map(|index| {
+                if index == finalized_checkpoint_index {
+                    let epoch_root = core::Hash::zero();
+                    let root = finalized_header.tree_hash_root();
+                    core::Hash::from(hash32_concat(epoch_root.as_bytes(), root.as_bytes()))
+                } else if index == current_sync_committee_index
+                    || index == next_sync_committee_index
+                {
+                    sync_committee_root
+                } else {
+                    core::Hash::repeat_byte(index as u8)
+                }
+            })
+            .collect::<Vec<_>>();
+        MerkleTree::create(&leaves, layout.depth)
+    }
+
+    /// Creates a finality update for the finalized header, and its attested header is signed by
+    /// the first `participants_count` members of the sync committee.
+    pub(crate) fn attest(
+        &self,
+        finalized_header: packed::Header,
+        participants_count: usize,
+    ) -> packed::FinalityUpdate {
+        let finalized_header: core::Header = finalized_header.unpack();
+        let attested_slot = self.attested_slot(&finalized_header);
+        let layout = BeaconStateLayout::at_slot(&self.spec, attested_slot);
+        let attested_state = self.attested_state(&layout, &finalized_header);
+        let finality_branch = {
+            let index = layout.leaf_index(layout.finalized_root_index >> 1);
+            let (_, proof) = attested_state.generate_proof(index, layout.depth).unwrap();
+            // The epoch of the finalized checkpoint is the sibling of its root.
+            iter::once(core::Hash::zero())
+                .chain(proof)
+                .collect::<Vec<_>>()
+        };
+        let attested_header = core::Header {
+            slot: attested_slot,
+            proposer_index: 1,
+            parent_root: core::Hash::repeat_byte(1),
+            state_root: attested_state.hash(),
+            body_root: core::Hash::repeat_byte(3),
+        };
+        let signature_slot = attested_header.slot + 1;
+        let signing_root = {
+            let epoch = self.spec.compute_epoch_at_slot(signature_slot - 1);
+            let domain = specs::helpers::compute_domain(
+                specs::altair::DOMAIN_SYNC_COMMITTEE,
+                self.spec.compute_fork_version(epoch),
+                self.spec.genesis_validators_root,
+            );
+            specs::helpers::compute_signing_root(attested_header.tree_hash_root(), domain)
+        };
+        let mut sync_committee_bits = vec![0u8; self.size() / 8];
+        let signatures = self.secret_keys[..participants_count]
+            .iter()
+            .enumerate()
+            .map(|(index, secret_key)| {
+                sync_committee_bits[index / 8] |= 1 << (index % 8);
+                secret_key.sign(signing_root.as_bytes(), bls::DST, &[])
+            })
+            .collect::<Vec<_>>();
+        let sync_committee_signature = if signatures.is_empty() {
+            [0u8; 96]
+        } else {
+            let signatures_refs = signatures.iter().collect::<Vec<_>>();
+            AggregateSignature::aggregate(&signatures_refs, false)
+                .unwrap()
+                .to_signature()
+                .compress()
+        };
+        core::FinalityUpdate {
+            attested_header,
+            finalized_header,
+            finality_branch,
+            sync_aggregate: core::SyncAggregate {
+                sync_committee_bits,
+                sync_committee_signature,
+            },
+            signature_slot,
+        }
+        .pack()
+    }
+}
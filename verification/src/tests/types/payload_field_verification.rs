@@ -0,0 +1,261 @@
+use alloc::vec::Vec;
+
+use merkle_proof::MerkleTree;
+use tree_hash::TreeHash as _;
+
+use crate::{
+    consensus_specs::{self as specs, helpers::Fork},
+    error::FieldVerificationError,
+    ssz,
+    types::{core, prelude::*},
+};
+
+const BLOCK_NUMBER: u64 = 17_034_870;
+const TIMESTAMP: u64 = 1_681_338_455;
+
+// Returns a slot in the fork, the count of fields in `ExecutionPayload` and the count of fields in
+// `BeaconBlockBody`.
+fn fork_params(fork: Fork) -> (u64, usize, usize) {
+    match fork {
+        Fork::Fulu => (
+            core::ChainSpec::MAINNET.compute_start_slot_at_epoch(specs::fulu::FORK_EPOCH) + 100,
+            specs::fulu::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+            specs::fulu::containers::BLOCK_BODY_FIELDS_COUNT,
+        ),
+        Fork::Electra => (
+            core::ChainSpec::MAINNET.compute_start_slot_at_epoch(specs::electra::FORK_EPOCH) + 100,
+            specs::electra::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+            specs::electra::containers::BLOCK_BODY_FIELDS_COUNT,
+        ),
+        Fork::Deneb => (
+            core::ChainSpec::MAINNET.compute_start_slot_at_epoch(specs::deneb::FORK_EPOCH) + 100,
+            specs::deneb::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+            specs::deneb::containers::BLOCK_BODY_FIELDS_COUNT,
+        ),
+        Fork::Capella => (
+            core::ChainSpec::MAINNET.compute_start_slot_at_epoch(specs::capella::FORK_EPOCH) + 100,
+            specs::capella::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+            specs::capella::containers::BLOCK_BODY_FIELDS_COUNT,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+// A mocked block body in the fork, returns the roots of all fields in the execution payload, the
+// header and a client which only has the header.
+fn mock_block(
+    fork: Fork,
+) -> (
+    Vec<core::Hash>,
+    MerkleTree,
+    MerkleTree,
+    core::Client,
+    core::Header,
+) {
+    // The indexes of these fields are not changed in all forks.
+    use specs::capella::containers;
+
+    let (slot, payload_fields_count, body_fields_count) = fork_params(fork);
+    let fields = (0..payload_fields_count)
+        .map(|index| match index {
+            containers::BLOCK_NUMBER_IN_EXECUTION_PAYLOAD_INDEX => BLOCK_NUMBER.tree_hash_root(),
+            containers::TIMESTAMP_IN_EXECUTION_PAYLOAD_INDEX => TIMESTAMP.tree_hash_root(),
+            _ => core::Hash::repeat_byte(index as u8),
+        })
+        .collect::<Vec<_>>();
+    let payload = MerkleTree::create(&fields, ssz::ceil_depth(payload_fields_count) as usize);
+    let body_fields = (0..body_fields_count)
+        .map(|index| {
+            if index == containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX {
+                payload.hash()
+            } else {
+                core::Hash::repeat_byte(0x80 | index as u8)
+            }
+        })
+        .collect::<Vec<_>>();
+    let body = MerkleTree::create(&body_fields, ssz::ceil_depth(body_fields_count) as usize);
+
+    let header = core::Header {
+        slot,
+        proposer_index: 1,
+        parent_root: core::Hash::repeat_byte(1),
+        state_root: core::Hash::repeat_byte(2),
+        body_root: body.hash(),
+    };
+    let client = super::single_header_client(&core::ChainSpec::MAINNET, &header);
+    (fields, payload, body, client, header)
+}
+
+fn mock_field_proof(
+    fork: Fork,
+    field_index: usize,
+) -> (core::Client, core::ExecutionPayloadFieldProof) {
+    use specs::capella::containers;
+
+    let (_, payload_fields_count, body_fields_count) = fork_params(fork);
+    let (fields, payload, body, client, header) = mock_block(fork);
+    let field_ssz_proof = {
+        let (_, mut proof) = payload
+            .generate_proof(field_index, ssz::ceil_depth(payload_fields_count) as usize)
+            .unwrap();
+        let (_, body_proof) = body
+            .generate_proof(
+                containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX,
+                ssz::ceil_depth(body_fields_count) as usize,
+            )
+            .unwrap();
+        proof.extend(body_proof);
+        proof
+    };
+    let field_proof = core::ExecutionPayloadFieldProof {
+        header,
+        field_index: field_index as u8,
+        field_root: fields[field_index],
+        header_mmr_proof: Vec::new(),
+        field_ssz_proof,
+    };
+    (client, field_proof)
+}
+
+#[test]
+fn check_generalized_indexes_in_deneb() {
+    use specs::deneb::{containers, generalized_index};
+
+    // The execution payload is at 25 in the block body, and has 32 leaves since Deneb.
+    assert_eq!(generalized_index::STATE_ROOT_IN_BLOCK_BODY, 25 * 32 + 2);
+    assert_eq!(generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY, 25 * 32 + 3);
+    assert_eq!(
+        generalized_index::execution_payload_field_in_block_body(
+            containers::EXCESS_BLOB_GAS_IN_EXECUTION_PAYLOAD_INDEX
+        ),
+        25 * 32 + 16
+    );
+    assert_eq!(
+        generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET,
+        ((25 * 32 + 13) * 2) << containers::TRANSACTIONS_DEPTH
+    );
+    assert_eq!(
+        generalized_index::WITHDRAWAL_IN_BLOCK_BODY_OFFSET,
+        ((25 * 32 + 14) * 2) << containers::WITHDRAWALS_DEPTH
+    );
+}
+
+#[test]
+fn verify_execution_payload_field_proofs() {
+    use specs::capella::containers;
+
+    for fork in [Fork::Capella, Fork::Deneb, Fork::Electra, Fork::Fulu] {
+        let (_, payload_fields_count, _) = fork_params(fork);
+        for field_index in 0..payload_fields_count {
+            let (client, field_proof) = mock_field_proof(fork, field_index);
+            let result = client.verify_packed_execution_payload_field_proof(
+                &core::ChainSpec::MAINNET,
+                field_proof.pack().as_reader(),
+            );
+            assert!(
+                result.is_ok(),
+                "failed to verify the proof for field#{field_index} in {fork:?}"
+            );
+        }
+    }
+
+    let (_, field_proof) = mock_field_proof(
+        Fork::Capella,
+        containers::BLOCK_NUMBER_IN_EXECUTION_PAYLOAD_INDEX,
+    );
+    assert_eq!(field_proof.block_number(), Some(BLOCK_NUMBER));
+    assert_eq!(field_proof.timestamp(), None);
+    assert_eq!(field_proof.block_hash(), None);
+
+    let (_, field_proof) = mock_field_proof(
+        Fork::Deneb,
+        containers::TIMESTAMP_IN_EXECUTION_PAYLOAD_INDEX,
+    );
+    assert_eq!(field_proof.timestamp(), Some(TIMESTAMP));
+
+    let (_, field_proof) = mock_field_proof(
+        Fork::Deneb,
+        containers::BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX,
+    );
+    assert_eq!(
+        field_proof.block_hash(),
+        Some(core::Hash::repeat_byte(
+            containers::BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX as u8
+        ))
+    );
+    assert_eq!(field_proof.block_number(), None);
+}
+
+#[test]
+fn verify_invalid_execution_payload_field_proofs() {
+    use specs::capella::containers;
+
+    let (client, field_proof) = mock_field_proof(
+        Fork::Capella,
+        containers::BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX,
+    );
+
+    let mut proof = field_proof.clone();
+    proof.field_root = core::Hash::repeat_byte(0xff);
+    let result = client.verify_packed_execution_payload_field_proof(
+        &core::ChainSpec::MAINNET,
+        proof.pack().as_reader(),
+    );
+    assert!(matches!(result, Err(FieldVerificationError::FieldSszProof)));
+
+    let mut proof = field_proof.clone();
+    proof.field_index = containers::BLOCK_NUMBER_IN_EXECUTION_PAYLOAD_INDEX as u8;
+    let result = client.verify_packed_execution_payload_field_proof(
+        &core::ChainSpec::MAINNET,
+        proof.pack().as_reader(),
+    );
+    assert!(matches!(result, Err(FieldVerificationError::FieldSszProof)));
+
+    let mut proof = field_proof.clone();
+    proof.field_index = containers::EXECUTION_PAYLOAD_FIELDS_COUNT as u8;
+    let result = client.verify_packed_execution_payload_field_proof(
+        &core::ChainSpec::MAINNET,
+        proof.pack().as_reader(),
+    );
+    assert!(matches!(result, Err(FieldVerificationError::FieldIndex)));
+
+    // The layout of the execution payload is changed in Deneb.
+    let (client, field_proof) = mock_field_proof(
+        Fork::Deneb,
+        containers::BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX,
+    );
+    let mut proof = field_proof.clone();
+    proof.header.slot = fork_params(Fork::Capella).0;
+    let result = proof.verify_in_header(&core::ChainSpec::MAINNET);
+    assert!(matches!(result, Err(FieldVerificationError::FieldSszProof)));
+
+    let mut proof = field_proof.clone();
+    proof.field_index = specs::deneb::containers::EXECUTION_PAYLOAD_FIELDS_COUNT as u8;
+    let result = client.verify_packed_execution_payload_field_proof(
+        &core::ChainSpec::MAINNET,
+        proof.pack().as_reader(),
+    );
+    assert!(matches!(result, Err(FieldVerificationError::FieldIndex)));
+
+    let mut proof = field_proof.clone();
+    proof.header.slot += 1;
+    let result = client.verify_packed_execution_payload_field_proof(
+        &core::ChainSpec::MAINNET,
+        proof.pack().as_reader(),
+    );
+    assert!(matches!(
+        result,
+        Err(FieldVerificationError::Unsynchronized)
+    ));
+
+    let mut proof = field_proof;
+    proof.header.proposer_index += 1;
+    let result = client.verify_packed_execution_payload_field_proof(
+        &core::ChainSpec::MAINNET,
+        proof.pack().as_reader(),
+    );
+    assert!(matches!(
+        result,
+        Err(FieldVerificationError::HeaderMmrProof)
+    ));
+}
@@ -3,15 +3,19 @@ use std::fs;
 
 use eth2_types::BeaconBlockHeader;
 
-use super::load_beacon_block_header_from_json_or_create_default;
+use super::{load_beacon_block_header_from_json_or_create_default, MockSyncCommittee};
 use crate::{
-    mmr,
+    consensus_specs as specs, mmr,
     tests::{find_json_files, setup},
     types::{core, packed, prelude::*},
 };
 
 const CASE_2_EMPTY_HEADER_INDEX: usize = 47;
 const CASE_3_CONTINUOUS_EMPTY_HEADERS_INDEX: usize = 29;
+// The headers in a client should be in the sync committee period of its bootstrap, or the next one
+// after the next sync committee is known.
+const CASE_1_MINIMAL_PRESET_PERIOD_START_INDEX: usize = 56;
+const CASE_4_NEW_PERIOD_BOOTSTRAP_INDEX: usize = 34;
 
 #[test]
 fn new_client_case_1_no_empty() {
@@ -23,9 +27,8 @@ fn new_client_case_1_no_empty() {
 }
 
 #[test]
-#[should_panic(expected = "failed to create client from proof update")]
 fn new_client_case_2_empty_at_the_start_of_updates() {
-    let skipped_count = CASE_2_EMPTY_HEADER_INDEX;
+    let skipped_count = CASE_2_EMPTY_HEADER_INDEX - 1;
     let param = NewClientParameter {
         case_id: 2,
         skipped_count_opt: Some(skipped_count),
@@ -43,7 +46,9 @@ fn new_client_case_2_empty_at_the_middle_of_updates() {
     new_client(param);
 }
 
+// The empty headers after the tip valid header are not attested.
 #[test]
+#[should_panic(expected = "failed to apply the first proof update")]
 fn new_client_case_2_empty_at_the_end_of_updates() {
     let total_count = CASE_2_EMPTY_HEADER_INDEX + 1;
     let param = NewClientParameter {
@@ -55,9 +60,8 @@ fn new_client_case_2_empty_at_the_end_of_updates() {
 }
 
 #[test]
-#[should_panic(expected = "failed to create client from proof update")]
 fn new_client_case_3_continuous_empty_at_the_start_of_updates() {
-    let skipped_count = CASE_3_CONTINUOUS_EMPTY_HEADERS_INDEX;
+    let skipped_count = CASE_3_CONTINUOUS_EMPTY_HEADERS_INDEX - 1;
     let param = NewClientParameter {
         case_id: 3,
         skipped_count_opt: Some(skipped_count),
@@ -75,7 +79,9 @@ fn new_client_case_3_continuous_empty_at_the_middle_of_updates() {
     new_client(param);
 }
 
+// The empty headers after the tip valid header are not attested.
 #[test]
+#[should_panic(expected = "failed to apply the first proof update")]
 fn new_client_case_3_continuous_empty_at_the_end_of_updates() {
     let total_count = CASE_3_CONTINUOUS_EMPTY_HEADERS_INDEX + 2;
     let param = NewClientParameter {
@@ -86,10 +92,78 @@ fn new_client_case_3_continuous_empty_at_the_end_of_updates() {
     new_client(param);
 }
 
+#[test]
+fn new_client_case_1_minimal_participation() {
+    let participants_count = specs::altair::SYNC_COMMITTEE_SIZE * 2 / 3 + 1;
+    let param = NewClientParameter {
+        case_id: 1,
+        participants_count_opt: Some(participants_count),
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+fn new_client_case_1_minimal_preset() {
+    let param = NewClientParameter {
+        case_id: 1,
+        spec_opt: Some(super::MINIMAL_DEVNET),
+        skipped_count_opt: Some(CASE_1_MINIMAL_PRESET_PERIOD_START_INDEX),
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to apply the first proof update")]
+fn new_client_case_1_insufficient_participation() {
+    let participants_count = specs::altair::SYNC_COMMITTEE_SIZE * 2 / 3;
+    let param = NewClientParameter {
+        case_id: 1,
+        participants_count_opt: Some(participants_count),
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to apply the first proof update")]
+fn new_client_case_1_unattested() {
+    let param = NewClientParameter {
+        case_id: 1,
+        unattested: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to apply the first proof update")]
+fn new_client_case_1_signed_by_another_committee() {
+    let param = NewClientParameter {
+        case_id: 1,
+        another_committee: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to apply the first proof update")]
+fn new_client_case_1_invalid_finality_branch() {
+    let param = NewClientParameter {
+        case_id: 1,
+        invalid_finality_branch: true,
+        ..Default::default()
+    };
+    new_client(param);
+}
+
 #[test]
 fn new_client_case_4_during_fork_capella() {
     let param = NewClientParameter {
         case_id: 4,
+        skipped_count_opt: Some(CASE_4_NEW_PERIOD_BOOTSTRAP_INDEX),
         ..Default::default()
     };
     new_client(param);
@@ -144,7 +218,9 @@ fn proof_update_case_2_empty_at_the_middle_of_updates() {
     proof_update(param);
 }
 
+// The empty headers after the tip valid header are not attested.
 #[test]
+#[should_panic(expected = "failed to update the proof in client")]
 fn proof_update_case_2_empty_at_the_end_of_updates() {
     let total_count = CASE_2_EMPTY_HEADER_INDEX + 1;
     let param = ProofUpdateParameter {
@@ -197,7 +273,9 @@ fn proof_update_case_3_continuous_empty_at_the_middle_of_updates_and_split() {
     proof_update(param);
 }
 
+// The empty headers after the tip valid header are not attested.
 #[test]
+#[should_panic(expected = "failed to update the proof in client")]
 fn proof_update_case_3_continuous_empty_at_the_end_of_updates() {
     let total_count = CASE_3_CONTINUOUS_EMPTY_HEADERS_INDEX + 2;
     let param = ProofUpdateParameter {
@@ -226,20 +304,59 @@ fn proof_update_case_5_after_fork_capella() {
     proof_update(param);
 }
 
+#[test]
+#[should_panic(expected = "failed to update the proof in client")]
+fn proof_update_case_1_unmatched_sync_committee() {
+    let param = ProofUpdateParameter {
+        case_id: 1,
+        another_committee: true,
+        ..Default::default()
+    };
+    proof_update(param);
+}
+
+#[test]
+fn proof_update_case_1_unknown_next_sync_committee() {
+    let param = ProofUpdateParameter {
+        case_id: 1,
+        unknown_next_sync_committee: true,
+        ..Default::default()
+    };
+    proof_update(param);
+}
+
+#[test]
+#[should_panic(expected = "failed to update the proof in client")]
+fn proof_update_case_4_rotate_to_unknown_sync_committee() {
+    let param = ProofUpdateParameter {
+        case_id: 4,
+        unknown_next_sync_committee: true,
+        ..Default::default()
+    };
+    proof_update(param);
+}
+
 #[derive(Default)]
 struct NewClientParameter {
     case_id: usize,
+    spec_opt: Option<core::ChainSpec>,
     skipped_count_opt: Option<usize>,
     total_count_opt: Option<usize>,
+    participants_count_opt: Option<usize>,
+    unattested: bool,
+    another_committee: bool,
+    invalid_finality_branch: bool,
     dump_dir_opt: Option<&'static str>,
 }
 
 fn new_client(param: NewClientParameter) {
     setup();
 
+    let spec = param.spec_opt.unwrap_or(core::ChainSpec::MAINNET);
+
     let case_dir = format!("mainnet/case-{}/beacon", param.case_id);
 
-    let headers = {
+    let mut headers = {
         let mut header_json_files = find_json_files(&case_dir, "block-header-slot-");
         if let Some(total_count) = param.total_count_opt {
             header_json_files.truncate(total_count);
@@ -252,20 +369,32 @@ fn new_client(param: NewClientParameter) {
             .collect::<Vec<BeaconBlockHeader>>()
     };
 
+    // The client is bootstrapped from the first header, then the other headers are applied as the
+    // first proof update.
+    let sync_committee = MockSyncCommittee::new_with_chain_spec(0, spec);
+    let bootstrap = sync_committee.bootstrap_headers(&mut headers);
+    let client = {
+        let header: core::Header = bootstrap.header().unpack();
+        let trusted_block_root = header.calc_cache().root;
+        let result =
+            core::Client::new_from_bootstrap(&spec, trusted_block_root, bootstrap.as_reader());
+        assert!(result.is_ok(), "failed to create client from bootstrap");
+        result.ok().unwrap()
+    };
+
     let minimal_slot: u64 = headers[0].slot.into();
     let maximal_slot: u64 = headers[headers.len() - 1].slot.into();
 
     let (tip_valid_header_root, packed_headers, headers_mmr_root, headers_mmr_proof) = {
         let store = mmr::lib::util::MemStore::default();
         let mut mmr = mmr::ClientRootMMR::new(0, &store);
-        let mut positions = Vec::with_capacity(headers.len());
-        let mut packed_headers = Vec::with_capacity(headers.len());
+        let mut positions = Vec::with_capacity(headers.len() - 1);
+        let mut packed_headers = Vec::with_capacity(headers.len() - 1);
         let mut tip_valid_header_root_opt = None;
 
-        for header in &headers {
+        for (index, header) in headers.iter().enumerate() {
             let header_slot: u64 = header.slot.into();
-            let index = header_slot - minimal_slot;
-            let position = mmr::lib::leaf_index_to_pos(index);
+            let position = mmr::lib::leaf_index_to_pos(header_slot - minimal_slot);
 
             let packed_header = packed::Header::from_ssz_header(header);
             let header: core::Header = packed_header.unpack();
@@ -276,8 +405,11 @@ fn new_client(param: NewClientParameter) {
             }
 
             mmr.push(header_with_cache.digest()).unwrap();
-            positions.push(position);
-            packed_headers.push(packed_header);
+            // The header of the bootstrap is already in the client.
+            if index > 0 {
+                positions.push(position);
+                packed_headers.push(packed_header);
+            }
         }
 
         let headers_mmr_root = mmr.get_root().unwrap();
@@ -300,43 +432,78 @@ fn new_client(param: NewClientParameter) {
         )
     };
 
+    let tip_valid_header = find_tip_valid_header(&packed_headers);
+    let next_committee_ssz_proof =
+        sync_committee.next_sync_committee_branch(tip_valid_header.clone());
+
     let expected_packed_client = core::Client {
         id: 0,
         minimal_slot,
         maximal_slot,
         tip_valid_header_root,
         headers_mmr_root: headers_mmr_root.unpack(),
+        sync_committee_period: spec
+            .compute_sync_committee_period_at_slot(tip_valid_header.slot().unpack()),
+        current_sync_committee_root: sync_committee.root(),
+        next_sync_committee_root: sync_committee.root(),
     }
     .pack();
 
-    let updates_items = packed_headers
-        .into_iter()
-        .map(|header| {
-            packed::FinalityUpdate::new_builder()
-                .finalized_header(header)
-                .build()
-        })
-        .collect::<Vec<_>>();
-    let updates = packed::FinalityUpdateVec::new_builder()
-        .set(updates_items)
-        .build();
+    let updates = {
+        let participants_count = param
+            .participants_count_opt
+            .unwrap_or_else(|| sync_committee.size());
+        let another_sync_committee_opt = if param.another_committee {
+            Some(MockSyncCommittee::new_with_chain_spec(1, spec))
+        } else {
+            None
+        };
+        let signer = another_sync_committee_opt
+            .as_ref()
+            .unwrap_or(&sync_committee);
+        let attest = |header| {
+            let update = signer.attest(header, participants_count);
+            if param.invalid_finality_branch {
+                let mut finality_branch: core::SszProof = update.finality_branch().unpack();
+                finality_branch[0] = core::Hash::repeat_byte(0xff);
+                update
+                    .as_builder()
+                    .finality_branch(finality_branch.pack())
+                    .build()
+            } else {
+                update
+            }
+        };
+        if param.unattested {
+            build_updates(packed_headers, None)
+        } else {
+            build_updates(packed_headers, Some(&attest))
+        }
+    };
 
     let packed_proof_update = packed::ProofUpdate::new_builder()
         .new_headers_mmr_root(headers_mmr_root)
+        .next_sync_committee_root(sync_committee.root().pack())
+        .next_committee_ssz_proof(next_committee_ssz_proof.pack())
         .new_headers_mmr_proof(headers_mmr_proof)
         .updates(updates)
+        .sync_committee(sync_committee.packed())
         .build();
 
     if let Some(ref dump_dir) = param.dump_dir_opt {
+        let bootstrap_filepath = format!("{dump_dir}/bootstrap-{minimal_slot}.data");
         let client_filepath = format!("{dump_dir}/client-{minimal_slot}_{maximal_slot}.data");
-        let proof_update_filepath =
-            format!("{dump_dir}/proof_update-{minimal_slot}_{maximal_slot}.data");
+        let proof_update_filepath = format!(
+            "{dump_dir}/proof_update-{}_{maximal_slot}.data",
+            minimal_slot + 1
+        );
+        fs::write(bootstrap_filepath, bootstrap.as_slice()).unwrap();
         fs::write(client_filepath, expected_packed_client.as_slice()).unwrap();
         fs::write(proof_update_filepath, packed_proof_update.as_slice()).unwrap();
     }
 
-    let result = core::Client::new_from_packed_proof_update(packed_proof_update.as_reader());
-    assert!(result.is_ok(), "failed to create client from proof update");
+    let result = client.try_apply_packed_proof_update(&spec, packed_proof_update.as_reader());
+    assert!(result.is_ok(), "failed to apply the first proof update");
 
     if let Ok(actual_client) = result {
         let actual_packed_client = actual_client.pack();
@@ -352,6 +519,8 @@ struct ProofUpdateParameter {
     case_id: usize,
     total_count_opt: Option<usize>,
     split_at_opt: Option<usize>,
+    another_committee: bool,
+    unknown_next_sync_committee: bool,
     dump_dir_opt: Option<&'static str>,
 }
 
@@ -377,29 +546,42 @@ fn proof_update(param: ProofUpdateParameter) {
     };
 
     let store = mmr::lib::util::MemStore::default();
-    let (tip_valid_header_root, headers_mmr_root, mmr) = {
+    let (tip_valid_header, headers_mmr_root, mmr) = {
         let mut mmr = mmr::ClientRootMMR::new(0, &store);
-        let mut tip_valid_header_root_opt = None;
+        let mut tip_valid_header_opt = None;
         for header in &headers_part1 {
             let header: core::Header = packed::Header::from_ssz_header(header).unpack();
             let header_with_cache = header.calc_cache();
+            mmr.push(header_with_cache.digest()).unwrap();
             if !header_with_cache.inner.is_empty() {
-                tip_valid_header_root_opt = Some(header_with_cache.root);
+                tip_valid_header_opt = Some(header_with_cache);
             }
-            mmr.push(header_with_cache.digest()).unwrap();
         }
         let headers_mmr_root = mmr.get_root().unwrap().unpack();
-        (tip_valid_header_root_opt.unwrap(), headers_mmr_root, mmr)
+        (tip_valid_header_opt.unwrap(), headers_mmr_root, mmr)
     };
 
+    let sync_committee = MockSyncCommittee::new(0);
     let minimal_slot = headers_part1[0].slot.into();
     let maximal_slot = headers_part1[headers_part1.len() - 1].slot.into();
     let client = core::Client {
         id: 0,
         minimal_slot,
         maximal_slot,
-        tip_valid_header_root,
+        tip_valid_header_root: tip_valid_header.root,
         headers_mmr_root,
+        sync_committee_period: core::ChainSpec::MAINNET
+            .compute_sync_committee_period_at_slot(tip_valid_header.inner.slot),
+        current_sync_committee_root: if param.another_committee {
+            MockSyncCommittee::new(1).root()
+        } else {
+            sync_committee.root()
+        },
+        next_sync_committee_root: if param.unknown_next_sync_committee {
+            core::Hash::zero()
+        } else {
+            sync_committee.root()
+        },
     };
 
     let (new_tip_valid_header_root, packed_headers, new_headers_mmr_root, new_headers_mmr_proof) = {
@@ -449,31 +631,35 @@ fn proof_update(param: ProofUpdateParameter) {
     let new_minimal_slot: u64 = headers_part2[0].slot.into();
     assert_eq!(new_minimal_slot, maximal_slot + 1);
     let new_maximal_slot = headers_part2[headers_part2.len() - 1].slot.into();
+    let new_tip_valid_header = find_tip_valid_header(&packed_headers);
+    let next_committee_ssz_proof =
+        sync_committee.next_sync_committee_branch(new_tip_valid_header.clone());
+
     let expected_packed_client = core::Client {
         id: 0,
         minimal_slot,
         maximal_slot: new_maximal_slot,
         tip_valid_header_root: new_tip_valid_header_root,
         headers_mmr_root: new_headers_mmr_root.unpack(),
+        sync_committee_period: core::ChainSpec::MAINNET
+            .compute_sync_committee_period_at_slot(new_tip_valid_header.slot().unpack()),
+        current_sync_committee_root: sync_committee.root(),
+        next_sync_committee_root: sync_committee.root(),
     }
     .pack();
 
-    let updates_items = packed_headers
-        .into_iter()
-        .map(|header| {
-            packed::FinalityUpdate::new_builder()
-                .finalized_header(header)
-                .build()
-        })
-        .collect::<Vec<_>>();
-    let updates = packed::FinalityUpdateVec::new_builder()
-        .set(updates_items)
-        .build();
+    let updates = {
+        let attest = |header| sync_committee.attest(header, sync_committee.size());
+        build_updates(packed_headers, Some(&attest))
+    };
 
     let packed_proof_update = packed::ProofUpdate::new_builder()
         .new_headers_mmr_root(new_headers_mmr_root)
+        .next_sync_committee_root(sync_committee.root().pack())
+        .next_committee_ssz_proof(next_committee_ssz_proof.pack())
         .new_headers_mmr_proof(new_headers_mmr_proof)
         .updates(updates)
+        .sync_committee(sync_committee.packed())
         .build();
 
     if let Some(ref dump_dir) = param.dump_dir_opt {
@@ -488,7 +674,8 @@ fn proof_update(param: ProofUpdateParameter) {
         fs::write(proof_update_filepath, packed_proof_update.as_slice()).unwrap();
     }
 
-    let result = client.try_apply_packed_proof_update(packed_proof_update.as_reader());
+    let result = client
+        .try_apply_packed_proof_update(&core::ChainSpec::MAINNET, packed_proof_update.as_reader());
     assert!(result.is_ok(), "failed to update the proof in client");
 
     if let Ok(actual_client) = result {
@@ -499,3 +686,42 @@ fn proof_update(param: ProofUpdateParameter) {
         );
     }
 }
+
+fn find_tip_valid_header(packed_headers: &[packed::Header]) -> packed::Header {
+    packed_headers
+        .iter()
+        .rev()
+        .find(|header| {
+            let header: core::Header = header.unpack();
+            !header.is_empty()
+        })
+        .cloned()
+        .unwrap()
+}
+
+// Only the update which contains the tip valid header is attested.
+fn build_updates(
+    packed_headers: Vec<packed::Header>,
+    attest_opt: Option<&dyn Fn(packed::Header) -> packed::FinalityUpdate>,
+) -> packed::FinalityUpdateVec {
+    let tip_index_opt = packed_headers.iter().rposition(|header| {
+        let header: core::Header = header.unpack();
+        !header.is_empty()
+    });
+    let updates_items = packed_headers
+        .into_iter()
+        .enumerate()
+        .map(|(index, header)| {
+            if let (Some(attest), true) = (attest_opt, Some(index) == tip_index_opt) {
+                attest(header)
+            } else {
+                packed::FinalityUpdate::new_builder()
+                    .finalized_header(header)
+                    .build()
+            }
+        })
+        .collect::<Vec<_>>();
+    packed::FinalityUpdateVec::new_builder()
+        .set(updates_items)
+        .build()
+}
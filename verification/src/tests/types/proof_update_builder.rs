@@ -0,0 +1,394 @@
+use alloc::{format, vec, vec::Vec};
+use std::{env, fs, io::Write as _, process};
+
+use eth2_types::BeaconBlockHeader;
+use eth_light_client_in_ckb_prover::{build_headers_window_proof, FileStore, ProofUpdateBuilder};
+
+use super::{
+    attest, bootstrap_builder, build_attested_proof_update, convert,
+    load_beacon_block_header_from_json_or_create_default, MockSyncCommittee,
+};
+use crate::{
+    consensus_specs as specs,
+    error::{HeadersWindowError, ProofUpdateError},
+    mmr,
+    tests::{find_json_files, setup},
+    types::{core, packed, prelude::*},
+};
+
+#[test]
+fn build_proof_updates_case_1() {
+    build_proof_updates(1, 16);
+}
+
+#[test]
+fn build_proof_updates_case_2() {
+    build_proof_updates(2, 16);
+}
+
+#[test]
+fn build_proof_updates_case_3() {
+    build_proof_updates(3, 29);
+}
+
+#[test]
+fn build_proof_updates_case_5() {
+    build_proof_updates(5, 20);
+}
+
+#[test]
+fn build_unattested_proof_update() {
+    setup();
+
+    let mut headers = load_headers(1);
+    let sync_committee = MockSyncCommittee::new(0);
+
+    let (mut builder, client) = bootstrap_builder(&sync_committee, &mut headers);
+    for header in &headers[1..] {
+        builder.push_header(header);
+    }
+
+    let result = builder.build();
+    assert!(result.is_err(), "unattested proof update should be failed");
+    assert_eq!(builder.client().maximal_slot, client.maximal_slot);
+    assert_eq!(builder.headers().len(), headers.len() - 1);
+
+    let tip_valid_header: packed::Header = convert(builder.tip_valid_header().unwrap().clone());
+    let tip_update = sync_committee.attest(tip_valid_header, specs::altair::SYNC_COMMITTEE_SIZE);
+    builder.set_tip_update(convert(tip_update));
+
+    let result = builder.build();
+    assert!(result.is_ok(), "failed to build proof update");
+    assert_eq!(
+        builder.client().maximal_slot,
+        u64::from(headers[headers.len() - 1].slot)
+    );
+    assert!(builder.headers().is_empty());
+}
+
+#[test]
+fn build_proof_update_with_only_empty_headers() {
+    setup();
+
+    // The header at index 47 in case 2 is empty.
+    let mut headers = load_headers(2);
+    let sync_committee = MockSyncCommittee::new(0);
+
+    let (mut builder, _) = bootstrap_builder(&sync_committee, &mut headers);
+    build_attested_proof_update(&mut builder, &sync_committee, &headers[1..47]);
+    let client = builder.client().clone();
+
+    builder.push_header(&headers[47]);
+    let result = builder.build();
+    // The error is from the instance of this crate in the prover.
+    assert_eq!(
+        result.err().map(|err| err as i8),
+        Some(ProofUpdateError::UnattestedTipHeader as i8)
+    );
+    assert_eq!(builder.client().maximal_slot, client.maximal_slot);
+    assert_eq!(builder.headers().len(), 1);
+
+    build_attested_proof_update(&mut builder, &sync_committee, &headers[48..]);
+    assert_eq!(
+        builder.client().maximal_slot,
+        u64::from(headers[headers.len() - 1].slot)
+    );
+}
+
+#[test]
+fn build_proof_updates_with_file_store() {
+    setup();
+
+    let mut headers = load_headers(1);
+    let sync_committee = MockSyncCommittee::new(0);
+    let bootstrap = sync_committee.bootstrap_headers(&mut headers);
+    let (headers_part1, headers_part2) = headers.split_at(headers.len() / 2);
+    let store_dir = env::temp_dir().join(format!("headers-mmr-{}", process::id()));
+    let _ = fs::remove_dir_all(&store_dir);
+
+    let client = {
+        let store = FileStore::open(&store_dir).unwrap();
+        let trusted_block_root = {
+            let header: core::Header = bootstrap.header().unpack();
+            header.calc_cache().root
+        };
+        let mut builder = ProofUpdateBuilder::with_bootstrap(
+            Default::default(),
+            trusted_block_root,
+            convert(bootstrap),
+            store,
+        )
+        .unwrap_or_else(|_| panic!("failed to create builder from bootstrap"));
+        for header in &headers_part1[1..] {
+            builder.push_header(header);
+        }
+        let tip_valid_header = builder.tip_valid_header().cloned().unwrap();
+        let (tip_update, _) = attest(&sync_committee, convert(tip_valid_header));
+        builder.set_tip_update(convert(tip_update));
+        let result = builder.build();
+        assert!(result.is_ok(), "failed to build proof update");
+        builder.client().clone()
+    };
+
+    // Simulate a crash during appending.
+    for (filename, len) in [("headers_mmr.data", 40), ("headers_mmr.index", 3)] {
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .open(store_dir.join(filename))
+            .unwrap();
+        file.write_all(&vec![0xff; len]).unwrap();
+    }
+
+    let store = FileStore::open(&store_dir).unwrap();
+    assert_eq!(store.leaves_count(), headers_part1.len() as u64);
+    let root: packed::HeaderDigest = convert(store.get_root().unwrap());
+    let root: core::HeaderDigest = root.unpack();
+    assert_eq!(root.children_hash, calc_headers_mmr_root(headers_part1));
+
+    let mut builder = ProofUpdateBuilder::with_client(client, store);
+    builder.set_sync_committee(convert(sync_committee.packed()));
+    for header in headers_part2 {
+        builder.push_header(header);
+    }
+    let tip_valid_header = builder.tip_valid_header().cloned().unwrap();
+    let (tip_update, _) = attest(&sync_committee, convert(tip_valid_header));
+    builder.set_tip_update(convert(tip_update));
+    let result = builder.build();
+    assert!(result.is_ok(), "failed to build proof update");
+
+    // Proofs for old headers could be generated without replaying.
+    let store = builder.store();
+    let headers_mmr_root: packed::HeaderDigest = convert(store.get_root().unwrap());
+    let indexes = [0, 7, headers.len() as u64 - 1];
+    let proof = {
+        let proof = store.gen_proof(&indexes).unwrap();
+        let items = proof
+            .proof_items()
+            .iter()
+            .cloned()
+            .map(convert)
+            .collect::<Vec<_>>();
+        mmr::MMRProof::new(proof.mmr_size(), items)
+    };
+    let digests_with_positions = indexes
+        .iter()
+        .map(|index| {
+            let header: core::Header =
+                packed::Header::from_ssz_header(&headers[*index as usize]).unpack();
+            (
+                mmr::lib::leaf_index_to_pos(*index),
+                header.calc_cache().digest(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let verified = proof
+        .verify(headers_mmr_root.clone(), digests_with_positions)
+        .unwrap();
+    assert!(
+        verified,
+        "failed to verify the MMR proof from the file store"
+    );
+
+    let headers_mmr_root: core::HeaderDigest = headers_mmr_root.unpack();
+    assert_eq!(
+        headers_mmr_root.children_hash,
+        calc_headers_mmr_root(&headers)
+    );
+
+    fs::remove_dir_all(&store_dir).unwrap();
+}
+
+fn load_headers(case_id: usize) -> Vec<BeaconBlockHeader> {
+    let case_dir = format!("mainnet/case-{case_id}/beacon");
+    find_json_files(&case_dir, "block-header-slot-")
+        .into_iter()
+        .map(load_beacon_block_header_from_json_or_create_default)
+        .collect()
+}
+
+fn build_proof_updates(case_id: usize, chunk_size: usize) {
+    setup();
+
+    let mut headers = load_headers(case_id);
+    let sync_committee = MockSyncCommittee::new(0);
+
+    let (mut builder, _) = bootstrap_builder(&sync_committee, &mut headers);
+    for chunk in headers[1..].chunks(chunk_size) {
+        for header in chunk {
+            builder.push_header(header);
+        }
+        if let Some(tip_valid_header) = builder.tip_valid_header().cloned() {
+            let (tip_update, next_sync_committee_opt) =
+                attest(&sync_committee, convert(tip_valid_header));
+            builder.set_tip_update(convert(tip_update));
+            if let Some((root, ssz_proof)) = next_sync_committee_opt {
+                builder.set_next_sync_committee(root, ssz_proof);
+            }
+        }
+        let pending_len = builder.headers().len();
+        let result = builder.build();
+        assert!(result.is_ok(), "failed to build proof update");
+
+        // The empty headers at the end of the chunk are kept for the next proof update.
+        if let Ok(packed_proof_update) = result {
+            let packed_proof_update: packed::ProofUpdate = convert(packed_proof_update);
+            let updates_len = packed_proof_update.updates().len();
+            assert_eq!(updates_len + builder.headers().len(), pending_len);
+            let last_header: core::Header = packed_proof_update
+                .updates()
+                .get(updates_len - 1)
+                .unwrap()
+                .finalized_header()
+                .unpack();
+            assert!(!last_header.is_empty());
+        }
+    }
+
+    let expected_headers_mmr_root = calc_headers_mmr_root(&headers);
+
+    let client = builder.client();
+    assert_eq!(client.minimal_slot, u64::from(headers[0].slot));
+    assert_eq!(
+        client.maximal_slot,
+        u64::from(headers[headers.len() - 1].slot)
+    );
+    assert_eq!(
+        client.headers_mmr_root.children_hash,
+        expected_headers_mmr_root
+    );
+}
+
+#[test]
+fn apply_proof_updates_with_minimal_updates_count() {
+    setup();
+
+    // There are empty slots in case 3, which are not counted.
+    let chunk_size = 16;
+    let mut headers = load_headers(3);
+    let sync_committee = MockSyncCommittee::new(0);
+    let spec = *sync_committee.spec();
+
+    let (mut builder, mut client) = bootstrap_builder(&sync_committee, &mut headers);
+    for chunk in headers[1..].chunks(chunk_size) {
+        let packed_proof_update = build_attested_proof_update(&mut builder, &sync_committee, chunk);
+        let reader = packed_proof_update.as_reader();
+        let valid_headers_count = chunk
+            .iter()
+            .filter(|header| {
+                let header: core::Header = packed::Header::from_ssz_header(header).unpack();
+                !header.is_empty()
+            })
+            .count();
+        let mut info = core::ClientInfo {
+            last_id: 0,
+            minimal_updates_count: valid_headers_count as u8 + 1,
+        };
+        let result = client.try_apply_packed_proof_update_with_info(&spec, &info, reader);
+        assert!(matches!(result, Err(ProofUpdateError::TooFewUpdates)));
+
+        info.minimal_updates_count -= 1;
+        client = client
+            .try_apply_packed_proof_update_with_info(&spec, &info, reader)
+            .unwrap_or_else(|_| panic!("failed to apply proof update"));
+    }
+}
+
+#[test]
+fn build_proof_updates_with_sliding_headers_window() {
+    setup();
+
+    let chunk_size = 16;
+    let window_size = 24;
+    let mut headers = load_headers(1);
+    let sync_committee = MockSyncCommittee::new(0);
+    let spec = *sync_committee.spec();
+
+    let (mut builder, mut client) = bootstrap_builder(&sync_committee, &mut headers);
+    for (index, chunk) in headers[1..].chunks(chunk_size).enumerate() {
+        let packed_proof_update = build_attested_proof_update(&mut builder, &sync_committee, chunk);
+        client = client
+            .try_apply_packed_proof_update(&spec, packed_proof_update.as_reader())
+            .unwrap_or_else(|_| panic!("failed to apply proof update"));
+
+        // Slide the headers window after every 2 proof updates.
+        if index % 2 == 1 {
+            let packed_proof: packed::HeadersWindowProof = convert(
+                builder
+                    .slide_headers_window(window_size, Default::default())
+                    .unwrap_or_else(|_| panic!("failed to slide headers window")),
+            );
+            client = client
+                .try_slide_headers_window(window_size, packed_proof.as_reader())
+                .unwrap_or_else(|_| panic!("failed to verify headers window"));
+            assert_eq!(client.minimal_slot, client.maximal_slot + 1 - window_size);
+        }
+    }
+
+    let window_start = headers.len() - window_size as usize;
+    assert_eq!(client.minimal_slot, u64::from(headers[window_start].slot));
+    let expected_headers_mmr_root = calc_headers_mmr_root(&headers[window_start..]);
+    assert_eq!(
+        client.headers_mmr_root.children_hash,
+        expected_headers_mmr_root
+    );
+}
+
+#[test]
+fn slide_headers_window_with_invalid_proofs() {
+    setup();
+
+    let mut headers = load_headers(1);
+    let sync_committee = MockSyncCommittee::new(0);
+    let spec = *sync_committee.spec();
+
+    let (mut builder, client) = bootstrap_builder(&sync_committee, &mut headers);
+    let packed_proof_update =
+        build_attested_proof_update(&mut builder, &sync_committee, &headers[1..32]);
+    let client = client
+        .try_apply_packed_proof_update(&spec, packed_proof_update.as_reader())
+        .unwrap_or_else(|_| panic!("failed to apply proof update"));
+
+    let (packed_proof, _) = build_headers_window_proof(builder.client(), builder.store(), 16)
+        .unwrap_or_else(|_| panic!("failed to build headers window proof"));
+    let packed_proof: packed::HeadersWindowProof = convert(packed_proof);
+    let result = client.try_slide_headers_window(16, packed_proof.as_reader());
+    assert!(result.is_ok());
+
+    // 16 slots are dropped, at least the minimal updates count of slots should be dropped.
+    let mut info = core::ClientInfo {
+        last_id: 0,
+        minimal_updates_count: 16,
+    };
+    let result = client.try_slide_headers_window_with_info(16, &info, packed_proof.as_reader());
+    assert!(result.is_ok());
+    info.minimal_updates_count += 1;
+    let result = client.try_slide_headers_window_with_info(16, &info, packed_proof.as_reader());
+    assert!(matches!(
+        result,
+        Err(HeadersWindowError::TooFewDroppedSlots)
+    ));
+
+    for window_size in [0, 32] {
+        let result = client.try_slide_headers_window(window_size, packed_proof.as_reader());
+        assert!(matches!(result, Err(HeadersWindowError::WindowSize)));
+    }
+
+    let result = client.try_slide_headers_window(8, packed_proof.as_reader());
+    assert!(matches!(result, Err(HeadersWindowError::DigestsCount)));
+
+    let mut proof: core::HeadersWindowProof = packed_proof.unpack();
+    proof.digests[0].children_hash = core::Hash::repeat_byte(1);
+    let result = client.try_slide_headers_window(16, proof.pack().as_reader());
+    assert!(matches!(result, Err(HeadersWindowError::HeadersMmrProof)));
+}
+
+fn calc_headers_mmr_root(headers: &[BeaconBlockHeader]) -> core::Hash {
+    let store = mmr::lib::util::MemStore::default();
+    let mut mmr = mmr::ClientRootMMR::new(0, &store);
+    for header in headers {
+        let header: core::Header = packed::Header::from_ssz_header(header).unpack();
+        mmr.push(header.calc_cache().digest()).unwrap();
+    }
+    let root: core::HeaderDigest = mmr.get_root().unwrap().unpack();
+    root.children_hash
+}
@@ -0,0 +1,195 @@
+use alloc::{vec, vec::Vec};
+
+use merkle_proof::MerkleTree;
+use rlp::RlpStream;
+
+use crate::{
+    consensus_specs as specs,
+    error::StateVerificationError,
+    trie,
+    types::{core, prelude::*},
+};
+
+const ADDRESS: core::Address = [0xaa; 20];
+
+// Creates a trie which only has one leaf, returns its root and the proof for the leaf.
+fn single_leaf_trie(key: &[u8], value: &[u8]) -> (core::Hash, core::MptProof) {
+    // The hex-prefix of a leaf with even nibbles.
+    let path = [&[0x20][..], &trie::keccak256(key)].concat();
+    let mut stream = RlpStream::new_list(2);
+    stream.append(&path);
+    stream.append(&value);
+    let leaf = stream.out().to_vec();
+    let root = core::Hash::from(trie::keccak256(&leaf));
+    (root, vec![leaf])
+}
+
+// Creates a block body which only the state root is meaningful.
+fn mock_block_body(state_root: core::Hash) -> (core::Hash, core::SszProof) {
+    use specs::capella::{containers, generalized_index};
+    let depth = containers::EXECUTION_PAYLOAD_DEPTH + containers::BLOCK_BODY_DEPTH;
+    let index = generalized_index::STATE_ROOT_IN_BLOCK_BODY - (1 << depth);
+    let leaves = (0..(1 << depth))
+        .map(|i| {
+            if i == index {
+                state_root
+            } else {
+                core::Hash::repeat_byte(i as u8)
+            }
+        })
+        .collect::<Vec<_>>();
+    let tree = MerkleTree::create(&leaves, depth as usize);
+    let (_, proof) = tree.generate_proof(index, depth as usize).unwrap();
+    (tree.hash(), proof)
+}
+
+fn mock_state_proof() -> (core::Client, core::StateProof) {
+    let storage_key = core::Hash::from_low_u64_be(1);
+    let storage_value = {
+        let mut value = [0u8; 32];
+        value[31] = 0x64;
+        value
+    };
+    let (storage_root, storage_mpt_proof) = single_leaf_trie(
+        storage_key.as_bytes(),
+        &trie::encode_storage_value(&storage_value),
+    );
+
+    let nonce = 3;
+    let balance = {
+        let mut balance = [0u8; 32];
+        balance[24..].copy_from_slice(&1_000_000_000_000_000_000u64.to_be_bytes());
+        balance
+    };
+    let code_hash = core::Hash::repeat_byte(0xcc);
+    let account = trie::encode_account(
+        nonce,
+        &balance,
+        storage_root.as_fixed_bytes(),
+        code_hash.as_fixed_bytes(),
+    );
+    let (state_root, account_mpt_proof) = single_leaf_trie(&ADDRESS, &account);
+
+    let (body_root, state_root_ssz_proof) = mock_block_body(state_root);
+    let header = core::Header {
+        slot: core::ChainSpec::MAINNET.compute_start_slot_at_epoch(specs::capella::FORK_EPOCH)
+            + 100,
+        proposer_index: 1,
+        parent_root: core::Hash::repeat_byte(1),
+        state_root: core::Hash::repeat_byte(2),
+        body_root,
+    };
+
+    let client = super::single_header_client(&core::ChainSpec::MAINNET, &header);
+
+    let state_proof = core::StateProof {
+        header,
+        state_root,
+        header_mmr_proof: Vec::new(),
+        state_root_ssz_proof,
+        address: ADDRESS,
+        nonce,
+        balance,
+        storage_root,
+        code_hash,
+        account_mpt_proof,
+        storage_proofs: vec![
+            core::StorageProof {
+                key: storage_key,
+                value: storage_value,
+                proof: storage_mpt_proof.clone(),
+            },
+            // An empty storage slot.
+            core::StorageProof {
+                key: core::Hash::from_low_u64_be(2),
+                value: [0u8; 32],
+                proof: storage_mpt_proof,
+            },
+        ],
+    };
+    (client, state_proof)
+}
+
+#[test]
+fn verify_state_proof() {
+    let (client, state_proof) = mock_state_proof();
+    let result =
+        client.verify_packed_state_proof(&core::ChainSpec::MAINNET, state_proof.pack().as_reader());
+    assert!(result.is_ok(), "failed to verify the state proof");
+}
+
+#[test]
+fn verify_state_proof_for_empty_account() {
+    let (client, mut state_proof) = mock_state_proof();
+    state_proof.address = [0xbb; 20];
+    state_proof.nonce = 0;
+    state_proof.balance = [0u8; 32];
+    state_proof.storage_root = trie::EMPTY_ROOT.into();
+    state_proof.code_hash = trie::EMPTY_CODE.into();
+    state_proof.storage_proofs.clear();
+    let result =
+        client.verify_packed_state_proof(&core::ChainSpec::MAINNET, state_proof.pack().as_reader());
+    assert!(
+        result.is_ok(),
+        "failed to verify the state proof for an empty account"
+    );
+}
+
+#[test]
+fn verify_invalid_state_proofs() {
+    let (client, state_proof) = mock_state_proof();
+
+    let mut proof = state_proof.clone();
+    proof.header.slot += 1;
+    let result =
+        client.verify_packed_state_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(StateVerificationError::Unsynchronized)
+    ));
+
+    let mut proof = state_proof.clone();
+    proof.header.proposer_index += 1;
+    let result =
+        client.verify_packed_state_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(StateVerificationError::HeaderMmrProof)
+    ));
+
+    let mut proof = state_proof.clone();
+    proof.state_root_ssz_proof[0] = core::Hash::zero();
+    let result =
+        client.verify_packed_state_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(StateVerificationError::StateRootSszProof)
+    ));
+
+    let mut proof = state_proof.clone();
+    proof.balance[31] = 1;
+    let result =
+        client.verify_packed_state_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(StateVerificationError::AccountMptProof)
+    ));
+
+    let mut proof = state_proof.clone();
+    proof.address = [0xbb; 20];
+    let result =
+        client.verify_packed_state_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(StateVerificationError::AccountMptProof)
+    ));
+
+    let mut proof = state_proof;
+    proof.storage_proofs[1].value[31] = 1;
+    let result =
+        client.verify_packed_state_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(StateVerificationError::StorageMptProof)
+    ));
+}
@@ -1,18 +1,26 @@
-use alloc::{format, vec, vec::Vec};
+use alloc::{format, vec::Vec};
 use std::fs::read_to_string;
 
 use eth2_types::{BeaconBlock, BeaconBlockHeader, MainnetEthSpec};
-use eth_light_client_in_ckb_prover::{CachedBeaconBlock, Receipts};
+use eth_light_client_in_ckb_prover::{
+    build_block_transactions_proof, build_execution_payload_field_proof, build_transaction_proof,
+    CachedBeaconBlock, Receipts,
+};
 use ethers_core::types::TransactionReceipt;
-use tree_hash::TreeHash as _;
 
-use super::load_beacon_block_header_from_json_or_create_default;
+use super::{
+    bootstrap_builder, build_attested_proof_update, convert,
+    load_beacon_block_header_from_json_or_create_default, MockSyncCommittee,
+};
 use crate::{
-    mmr,
+    consensus_specs as specs,
+    receipt::Receipt,
     tests::{find_json_file, find_json_files, setup},
     types::{core, packed, prelude::*},
 };
 
+const PROOF_UPDATE_CHUNK_SIZE: usize = 15;
+
 #[test]
 fn test_transaction_verification_case_1() {
     test_transaction_verification(1);
@@ -47,11 +55,15 @@ fn test_transaction_verification(case_id: usize) {
     let header_json_files = find_json_files(&beacon_dir, "block-header-slot-");
     let block_json_files = find_json_files(&beacon_dir, "block-slot-");
 
-    let headers = header_json_files
+    let mut headers = header_json_files
         .into_iter()
         .map(load_beacon_block_header_from_json_or_create_default)
         .collect::<Vec<BeaconBlockHeader>>();
 
+    // The headers are re-linked to the bootstrap, so are the blocks.
+    let sync_committee = MockSyncCommittee::new(0);
+    let (mut builder, _) = bootstrap_builder(&sync_committee, &mut headers);
+
     let blocks = block_json_files
         .into_iter()
         .filter_map(|file| {
@@ -60,31 +72,27 @@ fn test_transaction_verification(case_id: usize) {
             if json_value.get("code").is_some() {
                 None
             } else {
-                let block: BeaconBlock<MainnetEthSpec> =
+                let mut block: BeaconBlock<MainnetEthSpec> =
                     serde_json::from_value(json_value["data"]["message"].clone()).unwrap();
+                let header = headers
+                    .iter()
+                    .find(|header| header.slot == block.slot())
+                    .unwrap();
+                *block.parent_root_mut() = header.parent_root;
+                *block.state_root_mut() = header.state_root;
                 Some(block.into())
             }
         })
         .collect::<Vec<CachedBeaconBlock<MainnetEthSpec>>>();
 
-    let store = mmr::lib::util::MemStore::default();
-    let mmr = {
-        let mut mmr = mmr::ClientRootMMR::new(0, &store);
-        for header in &headers {
-            let header: core::Header = packed::Header::from_ssz_header(header).unpack();
-            mmr.push(header.calc_cache().digest()).unwrap();
-        }
-        mmr
-    };
-
-    let last_header = &headers[headers.len() - 1];
-    let client = core::Client {
-        id: 0,
-        minimal_slot: headers[0].slot.into(),
-        maximal_slot: last_header.slot.into(),
-        tip_valid_header_root: last_header.tree_hash_root(),
-        headers_mmr_root: mmr.get_root().unwrap().unpack(),
-    };
+    // The next sync committee should be known before the headers enter the next sync committee
+    // period, so its proof should be provided before the attested header enters that period.
+    for chunk in headers[1..].chunks(PROOF_UPDATE_CHUNK_SIZE) {
+        build_attested_proof_update(&mut builder, &sync_committee, chunk);
+    }
+    let spec = builder.spec();
+    let client = builder.client();
+    let headers_mmr = builder.headers_mmr();
 
     for (header, block) in headers
         .into_iter()
@@ -103,6 +111,25 @@ fn test_transaction_verification(case_id: usize) {
             slot, number, header.slot,
         );
 
+        let block_number_proof = build_execution_payload_field_proof(
+            spec,
+            client,
+            &headers_mmr,
+            &block,
+            specs::capella::containers::BLOCK_NUMBER_IN_EXECUTION_PAYLOAD_INDEX,
+        );
+        assert!(
+            block_number_proof.is_ok(),
+            "failed to build proof for the number of block#{}",
+            number
+        );
+        if let Ok(block_number_proof) = block_number_proof {
+            let block_number_proof: packed::ExecutionPayloadFieldProof =
+                convert(block_number_proof);
+            let block_number_proof: core::ExecutionPayloadFieldProof = block_number_proof.unpack();
+            assert_eq!(block_number_proof.block_number(), Some(number));
+        }
+
         let receipts: Receipts = {
             let json_filename = format!("block-receipts-number-{number}.json");
             let json_file = find_json_file(&execution_dir, &json_filename);
@@ -121,59 +148,100 @@ fn test_transaction_verification(case_id: usize) {
             slot, transactions_count, receipts_count,
         );
 
-        let header: core::Header = packed::Header::from_ssz_header(&header).unpack();
-
-        let receipts_root = receipts.root();
-        let receipts_root_ssz_proof = block.generate_receipts_root_proof_for_block_body();
-
-        let index = slot - client.minimal_slot;
-        let position = mmr::lib::leaf_index_to_pos(index.into());
-
-        let header_mmr_proof = mmr
-            .gen_proof(vec![position])
-            .unwrap()
-            .proof_items()
-            .iter()
-            .map(|item| item.unpack())
-            .collect::<Vec<_>>();
-
         for index in 0..receipts_count {
-            let transaction_ssz_proof = block.generate_transaction_proof_for_block_body(index);
-            let receipt_mpt_proof = receipts.generate_proof(index);
-
-            let proof = core::TransactionProof {
-                header: header.clone(),
-                transaction_index: index as u64,
-                receipts_root: receipts_root,
-                header_mmr_proof: header_mmr_proof.clone(),
-                transaction_ssz_proof,
-                receipt_mpt_proof,
-                receipts_root_ssz_proof: receipts_root_ssz_proof.clone(),
-            };
-
-            let payload = core::TransactionPayload {
-                transaction: block.transaction(index).unwrap().to_vec(),
-                receipt: receipts.encode_data(index),
-            };
-
-            let packed_proof = proof.pack();
-            let packed_payload = payload.pack();
-
-            let result = client.verify_packed_transaction_proof(packed_proof.as_reader());
+            let result =
+                build_transaction_proof(spec, client, &headers_mmr, &block, &receipts, index);
             assert!(
                 result.is_ok(),
-                "failed to verify packed proof for block#{}.transaction#{}",
+                "failed to build proof for block#{}.transaction#{}",
                 number,
                 index
             );
+            if let Ok((packed_proof, packed_payload)) = result {
+                let packed_proof: packed::TransactionProof = convert(packed_proof);
+                let packed_payload: packed::TransactionPayload = convert(packed_payload);
+
+                assert_eq!(
+                    packed_proof.header().as_slice(),
+                    packed::Header::from_ssz_header(&header).as_slice()
+                );
+                assert_eq!(
+                    packed_payload.transaction().raw_data(),
+                    &block.transaction(index).unwrap()[..]
+                );
+                assert_eq!(
+                    packed_payload.receipt().raw_data(),
+                    &receipts.encode_data(index)[..]
+                );
+
+                let proof: core::TransactionProof = packed_proof.unpack();
+                let result = proof
+                    .verify_packed_payload(&core::ChainSpec::MAINNET, packed_payload.as_reader());
+                assert!(
+                    result.is_ok(),
+                    "failed to verify packed payload for block#{}.transaction#{}",
+                    number,
+                    index
+                );
+
+                let receipt_data = packed_payload.receipt().raw_data();
+                let receipt = Receipt::decode(&receipt_data);
+                assert!(
+                    receipt.is_ok(),
+                    "failed to decode receipt for block#{}.transaction#{}",
+                    number,
+                    index
+                );
+                if let Ok(receipt) = receipt {
+                    check_receipt(&receipt, &receipts.original()[index]);
+                }
+            }
+        }
 
-            let result = proof.verify_packed_payload(packed_payload.as_reader());
+        if receipts_count == 0 {
+            continue;
+        }
+        let indexes = (0..receipts_count).collect::<Vec<_>>();
+        let result =
+            build_block_transactions_proof(spec, client, &headers_mmr, &block, &receipts, &indexes);
+        assert!(
+            result.is_ok(),
+            "failed to build proof for all transactions in block#{}",
+            number
+        );
+        if let Ok((packed_proof, packed_payloads)) = result {
+            let packed_proof: packed::BlockTransactionsProof = convert(packed_proof);
+            let packed_payloads: packed::TransactionPayloadVec = convert(packed_payloads);
+            assert_eq!(packed_proof.transactions().len(), receipts_count);
+            assert_eq!(packed_payloads.len(), receipts_count);
+
+            let proof: core::BlockTransactionsProof = packed_proof.unpack();
+            let result = proof
+                .verify_packed_payloads(&core::ChainSpec::MAINNET, packed_payloads.as_reader());
             assert!(
                 result.is_ok(),
-                "failed to verify packed payload for block#{}.transaction#{}",
-                number,
-                index
+                "failed to verify packed payloads for all transactions in block#{}",
+                number
             );
         }
     }
 }
+
+fn check_receipt(receipt: &Receipt, expected: &TransactionReceipt) {
+    let expected_type = expected
+        .transaction_type
+        .map(|tx_type| tx_type.as_u64())
+        .unwrap_or(0);
+    assert_eq!(u64::from(receipt.transaction_type()), expected_type);
+    let expected_status = expected.status.map(|status| status.as_u64() == 1);
+    assert_eq!(Some(receipt.status()), expected_status);
+    assert_eq!(receipt.logs().len(), expected.logs.len());
+    for (log, expected_log) in receipt.logs().zip(expected.logs.iter()) {
+        assert_eq!(log.address, expected_log.address.as_fixed_bytes());
+        assert_eq!(log.topics.len(), expected_log.topics.len());
+        for (topic, expected_topic) in log.topics.zip(expected_log.topics.iter()) {
+            assert_eq!(topic, expected_topic.as_fixed_bytes());
+        }
+        assert_eq!(log.data, &expected_log.data[..]);
+    }
+}
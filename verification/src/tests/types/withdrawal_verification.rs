@@ -0,0 +1,375 @@
+use alloc::vec::Vec;
+use std::fs::read_to_string;
+
+use eth2_hashing::hash32_concat;
+use eth2_types::{
+    BeaconBlock, ChainSpec, Epoch, EthSpec, MainnetEthSpec, MinimalEthSpec, Slot, Withdrawal,
+};
+use eth_light_client_in_ckb_prover::CachedBeaconBlock;
+use merkle_proof::MerkleTree;
+use tree_hash::TreeHash as _;
+
+use crate::{
+    consensus_specs::{self as specs, helpers::Fork},
+    error::WithdrawalVerificationError,
+    ssz,
+    tests::{find_json_files, setup},
+    types::{core, packed, prelude::*},
+};
+
+const WITHDRAWALS_COUNT: usize = 3;
+
+fn mock_withdrawal(index: usize) -> core::Withdrawal {
+    core::Withdrawal {
+        index: 1_000 + index as u64,
+        validator_index: 500_000 + index as u64,
+        address: [0xa0 | index as u8; 20],
+        amount: 1_000_000 * (index as u64 + 1),
+    }
+}
+
+// A mocked block in the fork which has a few withdrawals, returns a client which only has the
+// header of the block, and the proof for the withdrawal.
+fn mock_withdrawal_proof(
+    spec: &core::ChainSpec,
+    fork: Fork,
+    index: usize,
+) -> (core::Client, core::WithdrawalProof) {
+    // The indexes of these fields are not changed since Capella.
+    use specs::capella::containers;
+
+    let (payload_fields_count, body_fields_count) = match fork {
+        Fork::Fulu => (
+            specs::fulu::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+            specs::fulu::containers::BLOCK_BODY_FIELDS_COUNT,
+        ),
+        Fork::Electra => (
+            specs::electra::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+            specs::electra::containers::BLOCK_BODY_FIELDS_COUNT,
+        ),
+        Fork::Deneb => (
+            specs::deneb::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+            specs::deneb::containers::BLOCK_BODY_FIELDS_COUNT,
+        ),
+        Fork::Capella => (
+            containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+            containers::BLOCK_BODY_FIELDS_COUNT,
+        ),
+        _ => unreachable!(),
+    };
+    let slot = spec.compute_start_slot_at_epoch(spec.fork_config(fork).epoch) + 100;
+    let withdrawals_depth = spec.preset.withdrawals_depth() as usize;
+    let payload_depth = ssz::ceil_depth(payload_fields_count) as usize;
+    let body_depth = ssz::ceil_depth(body_fields_count) as usize;
+
+    let withdrawals = (0..WITHDRAWALS_COUNT)
+        .map(mock_withdrawal)
+        .collect::<Vec<_>>();
+    let withdrawal_hashes = withdrawals
+        .iter()
+        .map(|withdrawal| withdrawal.calc_root())
+        .collect::<Vec<_>>();
+    let withdrawals_data = MerkleTree::create(&withdrawal_hashes, withdrawals_depth);
+    let length_hash = ssz::length_hash(WITHDRAWALS_COUNT);
+    let withdrawals_root = core::Hash::from(hash32_concat(
+        withdrawals_data.hash().as_bytes(),
+        length_hash.as_bytes(),
+    ));
+
+    let payload_fields = (0..payload_fields_count)
+        .map(|index| {
+            if index == containers::WITHDRAWALS_IN_EXECUTION_PAYLOAD_INDEX {
+                withdrawals_root
+            } else {
+                core::Hash::repeat_byte(index as u8)
+            }
+        })
+        .collect::<Vec<_>>();
+    let payload = MerkleTree::create(&payload_fields, payload_depth);
+    let body_fields = (0..body_fields_count)
+        .map(|index| {
+            if index == containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX {
+                payload.hash()
+            } else {
+                core::Hash::repeat_byte(0x80 | index as u8)
+            }
+        })
+        .collect::<Vec<_>>();
+    let body = MerkleTree::create(&body_fields, body_depth);
+
+    let withdrawal_ssz_proof = {
+        let (_, mut proof) = withdrawals_data
+            .generate_proof(index, withdrawals_depth)
+            .unwrap();
+        proof.push(length_hash);
+        let (_, payload_proof) = payload
+            .generate_proof(
+                containers::WITHDRAWALS_IN_EXECUTION_PAYLOAD_INDEX,
+                payload_depth,
+            )
+            .unwrap();
+        proof.extend(payload_proof);
+        let (_, body_proof) = body
+            .generate_proof(
+                containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX,
+                body_depth,
+            )
+            .unwrap();
+        proof.extend(body_proof);
+        proof
+    };
+
+    let header = core::Header {
+        slot,
+        proposer_index: 1,
+        parent_root: core::Hash::repeat_byte(1),
+        state_root: core::Hash::repeat_byte(2),
+        body_root: body.hash(),
+    };
+    let client = super::single_header_client(spec, &header);
+
+    let withdrawal_proof = core::WithdrawalProof {
+        header,
+        withdrawal_index: index as u64,
+        withdrawal: withdrawals[index].clone(),
+        header_mmr_proof: Vec::new(),
+        withdrawal_ssz_proof,
+    };
+    (client, withdrawal_proof)
+}
+
+// The proof for the withdrawal in the block, the header of the block should be in the client.
+fn withdrawal_proof_in_block<T: EthSpec>(
+    block: &CachedBeaconBlock<T>,
+    index: usize,
+) -> core::WithdrawalProof {
+    let header: core::Header = packed::Header::from_ssz_header(&block.header()).unpack();
+    let withdrawal = block.withdrawal(index).unwrap();
+    core::WithdrawalProof {
+        header,
+        withdrawal_index: index as u64,
+        withdrawal: core::Withdrawal {
+            index: withdrawal.index,
+            validator_index: withdrawal.validator_index,
+            address: withdrawal.address.to_fixed_bytes(),
+            amount: withdrawal.amount,
+        },
+        header_mmr_proof: Vec::new(),
+        withdrawal_ssz_proof: block.generate_withdrawal_proof(index),
+    }
+}
+
+#[test]
+fn calculate_withdrawal_root() {
+    let withdrawal = mock_withdrawal(1);
+    let expected = {
+        let mut address_root = [0u8; 32];
+        address_root[..20].copy_from_slice(&withdrawal.address);
+        let left = hash32_concat(
+            withdrawal.index.tree_hash_root().as_bytes(),
+            withdrawal.validator_index.tree_hash_root().as_bytes(),
+        );
+        let right = hash32_concat(&address_root, withdrawal.amount.tree_hash_root().as_bytes());
+        core::Hash::from(hash32_concat(&left, &right))
+    };
+    assert_eq!(withdrawal.calc_root(), expected);
+}
+
+#[test]
+fn verify_withdrawal_proofs() {
+    for fork in [Fork::Capella, Fork::Deneb, Fork::Electra, Fork::Fulu] {
+        for index in 0..WITHDRAWALS_COUNT {
+            let (client, withdrawal_proof) =
+                mock_withdrawal_proof(&core::ChainSpec::MAINNET, fork, index);
+            let result = client.verify_packed_withdrawal_proof(
+                &core::ChainSpec::MAINNET,
+                withdrawal_proof.pack().as_reader(),
+            );
+            assert!(
+                result.is_ok(),
+                "failed to verify the proof for withdrawal#{index} in {fork:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn verify_withdrawal_proofs_in_mainnet_blocks() {
+    setup();
+
+    let spec = core::ChainSpec::MAINNET;
+    // The blocks in this case are after Capella, so they have withdrawals.
+    let blocks = find_json_files("mainnet/case-5/beacon", "block-slot-")
+        .into_iter()
+        .filter_map(|file| {
+            let json_str = read_to_string(file).unwrap();
+            let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+            if json_value.get("code").is_some() {
+                None
+            } else {
+                let block: BeaconBlock<MainnetEthSpec> =
+                    serde_json::from_value(json_value["data"]["message"].clone()).unwrap();
+                Some(block.into())
+            }
+        })
+        .collect::<Vec<CachedBeaconBlock<MainnetEthSpec>>>();
+    assert!(blocks.iter().any(|block| block.withdrawals_count() > 0));
+
+    for block in &blocks {
+        let header: core::Header = packed::Header::from_ssz_header(&block.header()).unpack();
+        let client = super::single_header_client(&spec, &header);
+        for index in 0..block.withdrawals_count() {
+            let proof = withdrawal_proof_in_block(block, index);
+            let result = client.verify_packed_withdrawal_proof(&spec, proof.pack().as_reader());
+            assert!(
+                result.is_ok(),
+                "failed to verify the proof for withdrawal#{index} in block#{}",
+                header.slot
+            );
+        }
+    }
+}
+
+#[test]
+fn verify_withdrawal_proofs_with_minimal_preset() {
+    let spec = super::MINIMAL_DEVNET;
+    for index in 0..WITHDRAWALS_COUNT {
+        let (client, withdrawal_proof) = mock_withdrawal_proof(&spec, Fork::Electra, index);
+        let result =
+            client.verify_packed_withdrawal_proof(&spec, withdrawal_proof.pack().as_reader());
+        assert!(
+            result.is_ok(),
+            "failed to verify the proof for withdrawal#{index} with the minimal preset"
+        );
+    }
+
+    let (client, mut withdrawal_proof) = mock_withdrawal_proof(&spec, Fork::Electra, 1);
+    withdrawal_proof.withdrawal_index = spec.preset.max_withdrawals_per_payload;
+    let result = client.verify_packed_withdrawal_proof(&spec, withdrawal_proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(WithdrawalVerificationError::WithdrawalIndex)
+    ));
+}
+
+#[test]
+fn verify_withdrawal_proofs_in_minimal_block() {
+    let spec = super::MINIMAL_DEVNET;
+    let withdrawals_count = 3;
+
+    // An Electra block of the minimal preset, which has a few withdrawals.
+    let block: CachedBeaconBlock<MinimalEthSpec> = {
+        let mut eth2_spec = ChainSpec::minimal();
+        eth2_spec.altair_fork_epoch = Some(Epoch::new(0));
+        eth2_spec.bellatrix_fork_epoch = Some(Epoch::new(0));
+        eth2_spec.capella_fork_epoch = Some(Epoch::new(0));
+        eth2_spec.deneb_fork_epoch = Some(Epoch::new(0));
+        eth2_spec.electra_fork_epoch = Some(Epoch::new(0));
+        let mut block = BeaconBlock::<MinimalEthSpec>::empty(&eth2_spec);
+        let BeaconBlock::Electra(ref mut inner) = block else {
+            panic!("failed to create an Electra block");
+        };
+        inner.slot = Slot::new(100);
+        for index in 0..withdrawals_count {
+            let withdrawal = Withdrawal {
+                index: 1_000 + index,
+                validator_index: 500_000 + index,
+                address: [0xa0 | index as u8; 20].into(),
+                amount: 1_000_000 * (index + 1),
+            };
+            inner
+                .body
+                .execution_payload
+                .execution_payload
+                .withdrawals
+                .push(withdrawal)
+                .unwrap();
+        }
+        block.into()
+    };
+    assert_eq!(block.withdrawals_count(), withdrawals_count as usize);
+
+    let header: core::Header = packed::Header::from_ssz_header(&block.header()).unpack();
+    let client = super::single_header_client(&spec, &header);
+
+    for index in 0..block.withdrawals_count() {
+        let proof = withdrawal_proof_in_block(&block, index);
+        let result = client.verify_packed_withdrawal_proof(&spec, proof.pack().as_reader());
+        assert!(
+            result.is_ok(),
+            "failed to verify the proof for withdrawal#{index} in a minimal block"
+        );
+
+        // The withdrawals are in a list of the minimal preset, whose depth is not the mainnet one.
+        let mainnet_preset_spec = core::ChainSpec {
+            preset: core::Preset::MAINNET,
+            ..spec
+        };
+        let result =
+            client.verify_packed_withdrawal_proof(&mainnet_preset_spec, proof.pack().as_reader());
+        assert!(matches!(
+            result,
+            Err(WithdrawalVerificationError::WithdrawalSszProof)
+        ));
+    }
+}
+
+#[test]
+fn verify_invalid_withdrawal_proofs() {
+    let (client, withdrawal_proof) =
+        mock_withdrawal_proof(&core::ChainSpec::MAINNET, Fork::Capella, 1);
+
+    let mut proof = withdrawal_proof.clone();
+    proof.withdrawal.amount += 1;
+    let result =
+        client.verify_packed_withdrawal_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(WithdrawalVerificationError::WithdrawalSszProof)
+    ));
+
+    let mut proof = withdrawal_proof.clone();
+    proof.withdrawal_index = 2;
+    let result =
+        client.verify_packed_withdrawal_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(WithdrawalVerificationError::WithdrawalSszProof)
+    ));
+
+    let mut proof = withdrawal_proof.clone();
+    proof.withdrawal_index = specs::capella::MAX_WITHDRAWALS_PER_PAYLOAD as u64;
+    let result =
+        client.verify_packed_withdrawal_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(WithdrawalVerificationError::WithdrawalIndex)
+    ));
+
+    let mut proof = withdrawal_proof.clone();
+    proof.header.slot += 1;
+    let result =
+        client.verify_packed_withdrawal_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(WithdrawalVerificationError::Unsynchronized)
+    ));
+
+    let mut proof = withdrawal_proof.clone();
+    proof.header.proposer_index += 1;
+    let result =
+        client.verify_packed_withdrawal_proof(&core::ChainSpec::MAINNET, proof.pack().as_reader());
+    assert!(matches!(
+        result,
+        Err(WithdrawalVerificationError::HeaderMmrProof)
+    ));
+
+    let mut proof = withdrawal_proof;
+    proof.header.slot =
+        core::ChainSpec::MAINNET.compute_start_slot_at_epoch(specs::capella::FORK_EPOCH) - 1;
+    let result = proof.verify_in_header(&core::ChainSpec::MAINNET);
+    assert!(matches!(
+        result,
+        Err(WithdrawalVerificationError::BeforeCapella)
+    ));
+}
@@ -1,3 +1,4 @@
 mod mmr;
+mod receipt;
 mod ssz;
 mod trie;
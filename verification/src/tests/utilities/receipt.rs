@@ -0,0 +1,138 @@
+use alloc::{vec, vec::Vec};
+
+use rlp::RlpStream;
+
+use crate::{
+    error::ReceiptError,
+    receipt::{Address, Receipt, Topic, LOGS_BLOOM_SIZE},
+};
+
+const CONTRACT: Address = [0x11; 20];
+const TOPIC_0: Topic = [0x22; 32];
+const TOPIC_1: Topic = [0x33; 32];
+
+struct MockLog {
+    address: Address,
+    topics: Vec<Topic>,
+    data: Vec<u8>,
+}
+
+fn mock_logs() -> Vec<MockLog> {
+    vec![
+        MockLog {
+            address: CONTRACT,
+            topics: vec![TOPIC_0, TOPIC_1],
+            data: vec![0x44; 64],
+        },
+        MockLog {
+            address: [0x55; 20],
+            topics: vec![],
+            data: vec![],
+        },
+    ]
+}
+
+fn encode_receipt(
+    transaction_type: u8,
+    status: &[u8],
+    logs_bloom_size: usize,
+    logs: &[MockLog],
+) -> Vec<u8> {
+    let mut stream = RlpStream::new_list(4);
+    stream.append(&status);
+    stream.append(&21000u64);
+    stream.append(&vec![0u8; logs_bloom_size]);
+    stream.begin_list(logs.len());
+    for log in logs {
+        stream.begin_list(3);
+        stream.append(&&log.address[..]);
+        stream.begin_list(log.topics.len());
+        for topic in &log.topics {
+            stream.append(&&topic[..]);
+        }
+        stream.append(&log.data);
+    }
+    let encoded = stream.out().to_vec();
+    if transaction_type == 0 {
+        encoded
+    } else {
+        [&[transaction_type][..], &encoded].concat()
+    }
+}
+
+#[test]
+fn test_decode_receipts() {
+    let logs = mock_logs();
+    for transaction_type in [0, 1, 2] {
+        for (status, expected_status) in [(&[][..], false), (&[1][..], true)] {
+            let data = encode_receipt(transaction_type, status, LOGS_BLOOM_SIZE, &logs);
+            let receipt = Receipt::decode(&data).ok().unwrap();
+
+            assert_eq!(receipt.transaction_type(), transaction_type);
+            assert_eq!(receipt.status(), expected_status);
+            assert_eq!(receipt.cumulative_gas_used(), 21000);
+            assert_eq!(receipt.logs_bloom(), &[0u8; LOGS_BLOOM_SIZE][..]);
+            assert_eq!(receipt.logs().len(), logs.len());
+            for (actual, expected) in receipt.logs().zip(logs.iter()) {
+                assert_eq!(actual.address, &expected.address);
+                assert_eq!(actual.topics.len(), expected.topics.len());
+                for (actual_topic, expected_topic) in actual.topics.zip(expected.topics.iter()) {
+                    assert_eq!(actual_topic, expected_topic);
+                }
+                assert_eq!(actual.data, &expected.data[..]);
+            }
+            assert!(receipt.log(logs.len()).is_none());
+        }
+    }
+}
+
+#[test]
+fn test_decode_invalid_receipts() {
+    let logs = mock_logs();
+
+    let data = encode_receipt(2, &[1], LOGS_BLOOM_SIZE, &logs);
+    let mut invalid_type = data.clone();
+    invalid_type[0] = 0;
+    let result = Receipt::decode(&invalid_type);
+    assert!(matches!(result, Err(ReceiptError::TransactionType)));
+    let result = Receipt::decode(&[]);
+    assert!(matches!(result, Err(ReceiptError::TransactionType)));
+
+    let mut trailing = data.clone();
+    trailing.push(0);
+    let result = Receipt::decode(&trailing);
+    assert!(matches!(result, Err(ReceiptError::Decode)));
+
+    let result = Receipt::decode(&data[..data.len() - 1]);
+    assert!(matches!(result, Err(ReceiptError::Decode)));
+
+    let invalid_bloom = encode_receipt(2, &[1], LOGS_BLOOM_SIZE - 1, &logs);
+    let result = Receipt::decode(&invalid_bloom);
+    assert!(matches!(result, Err(ReceiptError::Decode)));
+
+    // The receipts before the Byzantium fork contain the intermediate state root.
+    let pre_byzantium = encode_receipt(0, &[0x66; 32], LOGS_BLOOM_SIZE, &logs);
+    let result = Receipt::decode(&pre_byzantium);
+    assert!(matches!(result, Err(ReceiptError::Status)));
+}
+
+#[test]
+fn test_verify_logs() {
+    let data = encode_receipt(2, &[1], LOGS_BLOOM_SIZE, &mock_logs());
+    let receipt = Receipt::decode(&data).ok().unwrap();
+
+    let result = receipt.verify_log(0, &CONTRACT, &TOPIC_0);
+    assert!(result.is_ok(), "failed to verify the log");
+    if let Ok(log) = result {
+        assert_eq!(log.topics.last(), Some(&TOPIC_1));
+    }
+
+    let result = receipt.verify_log(0, &[0x55; 20], &TOPIC_0);
+    assert!(matches!(result, Err(ReceiptError::UnmatchedLogAddress)));
+    let result = receipt.verify_log(0, &CONTRACT, &TOPIC_1);
+    assert!(matches!(result, Err(ReceiptError::UnmatchedLogTopic)));
+    let result = receipt.verify_log(1, &[0x55; 20], &TOPIC_0);
+    assert!(matches!(result, Err(ReceiptError::UnmatchedLogTopic)));
+    let result = receipt.verify_log(2, &CONTRACT, &TOPIC_0);
+    assert!(matches!(result, Err(ReceiptError::LogIndex)));
+}
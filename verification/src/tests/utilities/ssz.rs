@@ -1,12 +1,17 @@
 use alloc::{vec, vec::Vec};
 
+use eth2_hashing::hash32_concat;
+use eth_light_client_in_ckb_prover::build_multiproof;
 use merkle_proof::MerkleTree;
 use ssz_derive::{Decode, Encode};
 use ssz_types::{typenum, VariableList};
 use tree_hash::{Hash256, TreeHash};
 use tree_hash_derive::TreeHash;
 
-use crate::ssz::{ceil_depth, floor_depth, length_hash, verify_merkle_proof};
+use crate::ssz::{
+    ceil_depth, floor_depth, get_helper_indices, length_hash, verify_merkle_proof,
+    verify_multiproof,
+};
 
 #[test]
 fn test_depth_calculation() {
@@ -113,6 +118,14 @@ impl DemoCache {
         proof
     }
 
+    fn generate_field_proof(&self, field_index: usize) -> Vec<Hash256> {
+        let leaves = vec![self.a_root, self.b_root, self.c_root];
+        let depth = Demo::FIELDS_DEPTH as usize;
+        let tree = MerkleTree::create(&leaves, depth);
+        let (_, proof) = tree.generate_proof(field_index, depth).unwrap();
+        proof
+    }
+
     fn generate_d_proof(&self, index: usize) -> Vec<Hash256> {
         let proof_c = self.generate_d_proof_for_c(index);
         let leaves = vec![self.a_root, self.b_root, self.c_root];
@@ -189,3 +202,200 @@ fn test_verify_merkle_proof() {
         );
     }
 }
+
+#[test]
+fn test_get_helper_indices() {
+    assert_eq!(get_helper_indices(&[8, 9, 14]), vec![15, 6, 5]);
+    assert_eq!(get_helper_indices(&[2]), vec![3]);
+    assert_eq!(get_helper_indices(&[4, 5, 6, 7]), Vec::<usize>::new());
+    assert_eq!(get_helper_indices(&[8, 2]), vec![9, 5, 3]);
+}
+
+#[test]
+fn test_verify_multiproof() {
+    let d_vec = (0u8..=10)
+        .map(|i| VariableList::from(vec![i, i + 1, i * 2]))
+        .collect::<Vec<_>>();
+    let demo = Demo {
+        a: 1,
+        b: 2,
+        c: VariableList::from(d_vec),
+    };
+    let cache = demo.generate_cache();
+    let root = cache.root();
+
+    let fields_offset = 2usize.pow(Demo::FIELDS_DEPTH);
+    let d_offset = {
+        let tmp = 2usize.pow(cache.c_depth + 1 + Demo::FIELDS_DEPTH);
+        tmp + tmp * Demo::C_FIELDS_INDEX / Demo::FIELDS_COUNT.next_power_of_two()
+    };
+    let d_indexes = [1, 2, 7];
+    let a_proof = cache.generate_field_proof(0);
+    let d_proofs = d_indexes
+        .iter()
+        .map(|index| cache.generate_d_proof(*index))
+        .collect::<Vec<_>>();
+
+    let mut leaves = vec![cache.a_root];
+    let mut indices = vec![fields_offset];
+    let mut branches = vec![(fields_offset, &a_proof[..])];
+    for (index, proof) in d_indexes.iter().zip(d_proofs.iter()) {
+        leaves.push(cache.d_roots[*index]);
+        indices.push(d_offset + index);
+        branches.push((d_offset + index, &proof[..]));
+    }
+
+    let helpers = build_multiproof(&branches).unwrap();
+    let branches_len = branches
+        .iter()
+        .map(|(_, branch)| branch.len())
+        .sum::<usize>();
+    assert!(helpers.len() < branches_len);
+    assert!(verify_multiproof(root, &leaves, &indices, &helpers));
+
+    let mut invalid_leaves = leaves.clone();
+    invalid_leaves[1] = cache.d_roots[0];
+    assert!(!verify_multiproof(
+        root,
+        &invalid_leaves,
+        &indices,
+        &helpers
+    ));
+
+    let mut invalid_indices = indices.clone();
+    invalid_indices[3] += 1;
+    assert!(!verify_multiproof(
+        root,
+        &leaves,
+        &invalid_indices,
+        &helpers
+    ));
+
+    assert!(!verify_multiproof(root, &leaves, &indices, &helpers[1..]));
+    assert!(!verify_multiproof(
+        root,
+        &leaves[1..],
+        &indices[1..],
+        &helpers
+    ));
+
+    // A multiproof for a single leaf is the same as its single-branch proof.
+    let index = d_offset + d_indexes[0];
+    let helpers = build_multiproof(&[(index, &d_proofs[0][..])]).unwrap();
+    assert_eq!(helpers, d_proofs[0]);
+    assert!(verify_multiproof(
+        root,
+        &[cache.d_roots[d_indexes[0]]],
+        &[index],
+        &helpers
+    ));
+}
+
+fn hash(hex: &str) -> Hash256 {
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect::<Vec<_>>();
+    Hash256::from_slice(&bytes)
+}
+
+// The nodes of a tree of 8 leaves, the leaves are at the generalized indices from 8 to 15.
+//
+// The hashes are calculated by SHA-256 directly, instead of by the functions to test.
+fn full_tree(leaves: &[Hash256; 8]) -> Vec<Hash256> {
+    let mut nodes = vec![Hash256::zero(); 16];
+    nodes[8..].copy_from_slice(leaves);
+    for index in (1..8).rev() {
+        let parent = hash32_concat(nodes[index * 2].as_bytes(), nodes[index * 2 + 1].as_bytes());
+        nodes[index] = Hash256::from(parent);
+    }
+    nodes
+}
+
+#[test]
+fn test_verify_multiproof_with_known_vectors() {
+    // The zero hashes of depth 0 to 3.
+    let zero_hashes = [
+        Hash256::zero(),
+        hash("f5a5fd42d16a20302798ef6ed309979b43003d2320d9f0e8ea9831a92759fb4b"),
+        hash("db56114e00fdd4c1f85c892bf35ac9a89289aaecb1ebd0a96cde606a748b5d71"),
+        hash("c78009fdf07fc56a11f122370658a353aaa542ed63e44c4bc15ff4cd105ab33c"),
+    ];
+    let nodes = full_tree(&[Hash256::zero(); 8]);
+    assert_eq!(nodes[1], zero_hashes[3]);
+    // The example in the consensus specs: the helpers of [8, 9, 14] are [15, 6, 5].
+    let indices = [8, 9, 14];
+    let leaves = [zero_hashes[0]; 3];
+    let helpers = [zero_hashes[0], zero_hashes[1], zero_hashes[1]];
+    assert!(verify_multiproof(
+        zero_hashes[3],
+        &leaves,
+        &indices,
+        &helpers
+    ));
+    assert!(!verify_multiproof(
+        zero_hashes[2],
+        &leaves,
+        &indices,
+        &helpers
+    ));
+
+    // The leaf at the generalized index `8 + i` is 32 bytes of `i + 1`.
+    let leaves = [1u8, 2, 3, 4, 5, 6, 7, 8].map(Hash256::repeat_byte);
+    let root = hash("c215a327df1243ec5271e106f8f03b979cadc0d1b8b10f214a5fdd11c0e6b612");
+    let nodes = full_tree(&leaves);
+    assert_eq!(nodes[1], root);
+    let helpers = [
+        hash("0808080808080808080808080808080808080808080808080808080808080808"),
+        hash("e38b0325ae6067640715997f0ef9f478600cbaeb410ebbceb7f749d90bd9d896"),
+        hash("505a9c6ac70bdffa46248e2025483f9fe997a0e31ed25559e448b73b7e02b9bd"),
+    ];
+    assert_eq!(helpers, [nodes[15], nodes[6], nodes[5]]);
+    let proved_leaves = [leaves[0], leaves[1], leaves[6]];
+    assert!(verify_multiproof(root, &proved_leaves, &indices, &helpers));
+
+    // The helpers in another order are not the nodes at the helper indices.
+    let unordered_helpers = [helpers[0], helpers[2], helpers[1]];
+    assert!(!verify_multiproof(
+        root,
+        &proved_leaves,
+        &indices,
+        &unordered_helpers
+    ));
+}
+
+#[test]
+fn test_verify_multiproof_edge_cases() {
+    let leaves = [1u8, 2, 3, 4, 5, 6, 7, 8].map(Hash256::repeat_byte);
+    let nodes = full_tree(&leaves);
+    let root = nodes[1];
+
+    // Duplicate indices share the helpers, and they should be the same leaf.
+    let indices = [8, 8, 14];
+    assert_eq!(get_helper_indices(&indices), vec![15, 9, 6, 5]);
+    let helpers = [nodes[15], nodes[9], nodes[6], nodes[5]];
+    let proved_leaves = [nodes[8], nodes[8], nodes[14]];
+    assert!(verify_multiproof(root, &proved_leaves, &indices, &helpers));
+    let proved_leaves = [nodes[8], nodes[9], nodes[14]];
+    assert!(!verify_multiproof(root, &proved_leaves, &indices, &helpers));
+
+    // An index is an ancestor of another one, the ancestor should be the one which is calculated
+    // from its descendants, instead of overriding them.
+    let indices = [4, 9];
+    assert_eq!(get_helper_indices(&indices), vec![8, 5, 3]);
+    let helpers = [nodes[8], nodes[5], nodes[3]];
+    let proved_leaves = [nodes[4], nodes[9]];
+    assert!(verify_multiproof(root, &proved_leaves, &indices, &helpers));
+    let proved_leaves = [nodes[4], nodes[10]];
+    assert!(!verify_multiproof(root, &proved_leaves, &indices, &helpers));
+    let proved_leaves = [nodes[5], nodes[9]];
+    assert!(!verify_multiproof(root, &proved_leaves, &indices, &helpers));
+
+    // Nothing is proved by an empty multiproof, even with the root as a helper.
+    assert!(get_helper_indices(&[]).is_empty());
+    assert!(!verify_multiproof(root, &[], &[], &[]));
+    assert!(!verify_multiproof(root, &[], &[], &[root]));
+
+    // The root itself isn't a leaf of a multiproof.
+    assert!(!verify_multiproof(root, &[root], &[1], &[]));
+}
@@ -1,6 +1,6 @@
 use rlp::{RlpStream, NULL_RLP};
 
-use crate::trie::{keccak256, EMPTY_ACCOUNT, EMPTY_CODE, EMPTY_ROOT};
+use crate::trie::{encode_account, keccak256, EMPTY_ACCOUNT, EMPTY_CODE, EMPTY_ROOT};
 
 #[test]
 fn test_empty_value() {
@@ -18,4 +18,7 @@ fn test_empty_value() {
     stream.append(&EMPTY_CODE.as_ref());
     let empty_account = stream.out().to_vec();
     assert_eq!(&empty_account, &EMPTY_ACCOUNT);
+
+    let empty_account = encode_account(0, &[0u8; 32], &EMPTY_ROOT, &EMPTY_CODE);
+    assert_eq!(&empty_account, &EMPTY_ACCOUNT);
 }
@@ -24,6 +24,44 @@ impl Pack<packed::Bytes> for core::Bytes {
     }
 }
 
+impl Pack<packed::Address> for core::Address {
+    fn pack(&self) -> packed::Address {
+        packed::Address::new_unchecked(molecule::bytes::Bytes::from(self.to_vec()))
+    }
+}
+
+impl Pack<packed::ForkVersion> for core::ForkVersion {
+    fn pack(&self) -> packed::ForkVersion {
+        packed::ForkVersion::new_unchecked(molecule::bytes::Bytes::from(self.to_vec()))
+    }
+}
+
+impl Pack<packed::Uint256> for core::Uint256 {
+    fn pack(&self) -> packed::Uint256 {
+        packed::Uint256::new_unchecked(molecule::bytes::Bytes::from(self.to_vec()))
+    }
+}
+
+impl Pack<packed::BlsPubkey> for core::BlsPubkey {
+    fn pack(&self) -> packed::BlsPubkey {
+        packed::BlsPubkey::new_unchecked(molecule::bytes::Bytes::from(self.to_vec()))
+    }
+}
+
+impl Pack<packed::BlsSignature> for core::BlsSignature {
+    fn pack(&self) -> packed::BlsSignature {
+        packed::BlsSignature::new_unchecked(molecule::bytes::Bytes::from(self.to_vec()))
+    }
+}
+
+impl Pack<packed::BlsPubkeyVec> for core::BlsPubkeyVec {
+    fn pack(&self) -> packed::BlsPubkeyVec {
+        packed::BlsPubkeyVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
 impl Pack<packed::SszProof> for core::SszProof {
     fn pack(&self) -> packed::SszProof {
         packed::SszProof::new_builder()
@@ -68,12 +106,40 @@ impl Pack<packed::Header> for core::Header {
     }
 }
 
+impl Pack<packed::HeaderVec> for core::HeaderVec {
+    fn pack(&self) -> packed::HeaderVec {
+        packed::HeaderVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
+impl Pack<packed::SyncCommittee> for core::SyncCommittee {
+    fn pack(&self) -> packed::SyncCommittee {
+        packed::SyncCommittee::new_builder()
+            .pubkeys(self.pubkeys.pack())
+            .aggregate_pubkey(self.aggregate_pubkey.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::SyncAggregate> for core::SyncAggregate {
+    fn pack(&self) -> packed::SyncAggregate {
+        packed::SyncAggregate::new_builder()
+            .sync_committee_bits(self.sync_committee_bits.pack())
+            .sync_committee_signature(self.sync_committee_signature.pack())
+            .build()
+    }
+}
+
 impl Pack<packed::FinalityUpdate> for core::FinalityUpdate {
     fn pack(&self) -> packed::FinalityUpdate {
         packed::FinalityUpdate::new_builder()
             .attested_header(self.attested_header.pack())
             .finalized_header(self.finalized_header.pack())
             .finality_branch(self.finality_branch.pack())
+            .sync_aggregate(self.sync_aggregate.pack())
+            .signature_slot(self.signature_slot.pack())
             .build()
     }
 }
@@ -90,9 +156,21 @@ impl Pack<packed::ProofUpdate> for core::ProofUpdate {
     fn pack(&self) -> packed::ProofUpdate {
         packed::ProofUpdate::new_builder()
             .new_headers_mmr_root(self.new_headers_mmr_root.pack())
+            .next_sync_committee_root(self.next_sync_committee_root.pack())
             .next_committee_ssz_proof(self.next_committee_ssz_proof.pack())
             .new_headers_mmr_proof(self.new_headers_mmr_proof.pack())
             .updates(self.updates.pack())
+            .sync_committee(self.sync_committee.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::LightClientBootstrap> for core::LightClientBootstrap {
+    fn pack(&self) -> packed::LightClientBootstrap {
+        packed::LightClientBootstrap::new_builder()
+            .header(self.header.pack())
+            .current_sync_committee(self.current_sync_committee.pack())
+            .current_sync_committee_branch(self.current_sync_committee_branch.pack())
             .build()
     }
 }
@@ -120,6 +198,190 @@ impl Pack<packed::TransactionPayload> for core::TransactionPayload {
     }
 }
 
+impl Pack<packed::TransactionInBlockProof> for core::TransactionInBlockProof {
+    fn pack(&self) -> packed::TransactionInBlockProof {
+        packed::TransactionInBlockProof::new_builder()
+            .transaction_index(self.transaction_index.pack())
+            .transaction_ssz_proof(self.transaction_ssz_proof.pack())
+            .receipt_mpt_proof(self.receipt_mpt_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::TransactionInBlockProofVec> for core::TransactionInBlockProofVec {
+    fn pack(&self) -> packed::TransactionInBlockProofVec {
+        packed::TransactionInBlockProofVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
+impl Pack<packed::BlockTransactionsProof> for core::BlockTransactionsProof {
+    fn pack(&self) -> packed::BlockTransactionsProof {
+        packed::BlockTransactionsProof::new_builder()
+            .header(self.header.pack())
+            .receipts_root(self.receipts_root.pack())
+            .header_mmr_proof(self.header_mmr_proof.pack())
+            .receipts_root_ssz_proof(self.receipts_root_ssz_proof.pack())
+            .transactions(self.transactions.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::TransactionPayloadVec> for core::TransactionPayloadVec {
+    fn pack(&self) -> packed::TransactionPayloadVec {
+        packed::TransactionPayloadVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
+impl Pack<packed::ExecutionPayloadFieldProof> for core::ExecutionPayloadFieldProof {
+    fn pack(&self) -> packed::ExecutionPayloadFieldProof {
+        packed::ExecutionPayloadFieldProof::new_builder()
+            .header(self.header.pack())
+            .field_index(self.field_index.into())
+            .field_root(self.field_root.pack())
+            .header_mmr_proof(self.header_mmr_proof.pack())
+            .field_ssz_proof(self.field_ssz_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::StorageProof> for core::StorageProof {
+    fn pack(&self) -> packed::StorageProof {
+        packed::StorageProof::new_builder()
+            .key(self.key.pack())
+            .value(self.value.pack())
+            .proof(self.proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::StorageProofVec> for core::StorageProofVec {
+    fn pack(&self) -> packed::StorageProofVec {
+        packed::StorageProofVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
+impl Pack<packed::StateProof> for core::StateProof {
+    fn pack(&self) -> packed::StateProof {
+        packed::StateProof::new_builder()
+            .header(self.header.pack())
+            .state_root(self.state_root.pack())
+            .header_mmr_proof(self.header_mmr_proof.pack())
+            .state_root_ssz_proof(self.state_root_ssz_proof.pack())
+            .address(self.address.pack())
+            .nonce(self.nonce.pack())
+            .balance(self.balance.pack())
+            .storage_root(self.storage_root.pack())
+            .code_hash(self.code_hash.pack())
+            .account_mpt_proof(self.account_mpt_proof.pack())
+            .storage_proofs(self.storage_proofs.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::Withdrawal> for core::Withdrawal {
+    fn pack(&self) -> packed::Withdrawal {
+        packed::Withdrawal::new_builder()
+            .index(self.index.pack())
+            .validator_index(self.validator_index.pack())
+            .address(self.address.pack())
+            .amount(self.amount.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::WithdrawalProof> for core::WithdrawalProof {
+    fn pack(&self) -> packed::WithdrawalProof {
+        packed::WithdrawalProof::new_builder()
+            .header(self.header.pack())
+            .withdrawal_index(self.withdrawal_index.pack())
+            .withdrawal(self.withdrawal.pack())
+            .header_mmr_proof(self.header_mmr_proof.pack())
+            .withdrawal_ssz_proof(self.withdrawal_ssz_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::HistoricalBlockProof> for core::HistoricalBlockProof {
+    fn pack(&self) -> packed::HistoricalBlockProof {
+        packed::HistoricalBlockProof::new_builder()
+            .anchor_header(self.anchor_header.pack())
+            .header(self.header.pack())
+            .anchor_header_mmr_proof(self.anchor_header_mmr_proof.pack())
+            .header_ssz_proof(self.header_ssz_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::HeadersWindowProof> for core::HeadersWindowProof {
+    fn pack(&self) -> packed::HeadersWindowProof {
+        let digests = packed::HeaderDigestVec::new_builder()
+            .set(self.digests.iter().map(|digest| digest.pack()).collect())
+            .build();
+        packed::HeadersWindowProof::new_builder()
+            .digests(digests)
+            .digests_mmr_proof(self.digests_mmr_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::ClientUpdate> for core::ClientUpdate {
+    fn pack(&self) -> packed::ClientUpdate {
+        match self {
+            Self::ProofUpdate(update) => packed::ClientUpdate::new_builder().set(update.pack()),
+            Self::HeadersWindowProof(proof) => {
+                packed::ClientUpdate::new_builder().set(proof.pack())
+            }
+        }
+        .build()
+    }
+}
+
+impl Pack<packed::ForkConfig> for core::ForkConfig {
+    fn pack(&self) -> packed::ForkConfig {
+        packed::ForkConfig::new_builder()
+            .version(self.version.pack())
+            .epoch(self.epoch.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::ForkConfigVec> for core::ForkConfigVec {
+    fn pack(&self) -> packed::ForkConfigVec {
+        packed::ForkConfigVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
+impl Pack<packed::Preset> for core::Preset {
+    fn pack(&self) -> packed::Preset {
+        packed::Preset::new_builder()
+            .slots_per_epoch(self.slots_per_epoch.pack())
+            .epochs_per_sync_committee_period(self.epochs_per_sync_committee_period.pack())
+            .sync_committee_size(self.sync_committee_size.pack())
+            .max_withdrawals_per_payload(self.max_withdrawals_per_payload.pack())
+            .slots_per_historical_root(self.slots_per_historical_root.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::ChainSpec> for core::ChainSpec {
+    fn pack(&self) -> packed::ChainSpec {
+        packed::ChainSpec::new_builder()
+            .genesis_validators_root(self.genesis_validators_root.pack())
+            .genesis_fork_version(self.genesis_fork_version.pack())
+            .preset(self.preset.pack())
+            .forks(self.forks.pack())
+            .build()
+    }
+}
+
 impl Pack<packed::ClientInfo> for core::ClientInfo {
     fn pack(&self) -> packed::ClientInfo {
         packed::ClientInfo::new_builder()
@@ -137,6 +399,9 @@ impl Pack<packed::Client> for core::Client {
             .maximal_slot(self.maximal_slot.pack())
             .tip_valid_header_root(self.tip_valid_header_root.pack())
             .headers_mmr_root(self.headers_mmr_root.pack())
+            .sync_committee_period(self.sync_committee_period.pack())
+            .current_sync_committee_root(self.current_sync_committee_root.pack())
+            .next_sync_committee_root(self.next_sync_committee_root.pack())
             .build()
     }
 }
@@ -146,6 +411,10 @@ impl Pack<packed::ClientTypeArgs> for core::ClientTypeArgs {
         packed::ClientTypeArgs::new_builder()
             .type_id(self.type_id.pack())
             .cells_count(self.cells_count.into())
+            .chain_spec(self.chain_spec.pack())
+            .trusted_block_root(self.trusted_block_root.pack())
+            .headers_window_size(self.headers_window_size.pack())
+            .owner_lock_hash(self.owner_lock_hash.pack())
             .build()
     }
 }
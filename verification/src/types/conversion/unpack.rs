@@ -1,6 +1,9 @@
 use molecule::prelude::*;
 
-use crate::types::{core, packed, prelude::*};
+use crate::{
+    consensus_specs::helpers::Fork,
+    types::{core, packed, prelude::*},
+};
 
 macro_rules! impl_conversion_for_entity_unpack {
     ($name:ident) => {
@@ -35,6 +38,58 @@ impl<'r> Unpack<core::Bytes> for packed::BytesReader<'r> {
 }
 impl_conversion_for_entity_unpack!(Bytes);
 
+impl<'r> Unpack<core::Address> for packed::AddressReader<'r> {
+    fn unpack(&self) -> core::Address {
+        let mut b = [0u8; 20];
+        b.copy_from_slice(self.as_slice());
+        b
+    }
+}
+impl_conversion_for_entity_unpack!(Address);
+
+impl<'r> Unpack<core::ForkVersion> for packed::ForkVersionReader<'r> {
+    fn unpack(&self) -> core::ForkVersion {
+        let mut b = [0u8; 4];
+        b.copy_from_slice(self.as_slice());
+        b
+    }
+}
+impl_conversion_for_entity_unpack!(ForkVersion);
+
+impl<'r> Unpack<core::Uint256> for packed::Uint256Reader<'r> {
+    fn unpack(&self) -> core::Uint256 {
+        let mut b = [0u8; 32];
+        b.copy_from_slice(self.as_slice());
+        b
+    }
+}
+impl_conversion_for_entity_unpack!(Uint256);
+
+impl<'r> Unpack<core::BlsPubkey> for packed::BlsPubkeyReader<'r> {
+    fn unpack(&self) -> core::BlsPubkey {
+        let mut b = [0u8; 48];
+        b.copy_from_slice(self.as_slice());
+        b
+    }
+}
+impl_conversion_for_entity_unpack!(BlsPubkey);
+
+impl<'r> Unpack<core::BlsSignature> for packed::BlsSignatureReader<'r> {
+    fn unpack(&self) -> core::BlsSignature {
+        let mut b = [0u8; 96];
+        b.copy_from_slice(self.as_slice());
+        b
+    }
+}
+impl_conversion_for_entity_unpack!(BlsSignature);
+
+impl<'r> Unpack<core::BlsPubkeyVec> for packed::BlsPubkeyVecReader<'r> {
+    fn unpack(&self) -> core::BlsPubkeyVec {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(BlsPubkeyVec);
+
 impl<'r> Unpack<core::SszProof> for packed::SszProofReader<'r> {
     fn unpack(&self) -> core::SszProof {
         self.iter().map(|v| v.unpack()).collect()
@@ -78,12 +133,41 @@ impl<'r> Unpack<core::Header> for packed::HeaderReader<'r> {
 }
 impl_conversion_for_entity_unpack!(Header);
 
+impl<'r> Unpack<core::HeaderVec> for packed::HeaderVecReader<'r> {
+    fn unpack(&self) -> core::HeaderVec {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(HeaderVec);
+
+impl<'r> Unpack<core::SyncCommittee> for packed::SyncCommitteeReader<'r> {
+    fn unpack(&self) -> core::SyncCommittee {
+        core::SyncCommittee {
+            pubkeys: self.pubkeys().unpack(),
+            aggregate_pubkey: self.aggregate_pubkey().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(SyncCommittee);
+
+impl<'r> Unpack<core::SyncAggregate> for packed::SyncAggregateReader<'r> {
+    fn unpack(&self) -> core::SyncAggregate {
+        core::SyncAggregate {
+            sync_committee_bits: self.sync_committee_bits().unpack(),
+            sync_committee_signature: self.sync_committee_signature().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(SyncAggregate);
+
 impl<'r> Unpack<core::FinalityUpdate> for packed::FinalityUpdateReader<'r> {
     fn unpack(&self) -> core::FinalityUpdate {
         core::FinalityUpdate {
             attested_header: self.attested_header().unpack(),
             finalized_header: self.finalized_header().unpack(),
             finality_branch: self.finality_branch().unpack(),
+            sync_aggregate: self.sync_aggregate().unpack(),
+            signature_slot: self.signature_slot().unpack(),
         }
     }
 }
@@ -100,14 +184,27 @@ impl<'r> Unpack<core::ProofUpdate> for packed::ProofUpdateReader<'r> {
     fn unpack(&self) -> core::ProofUpdate {
         core::ProofUpdate {
             new_headers_mmr_root: self.new_headers_mmr_root().unpack(),
+            next_sync_committee_root: self.next_sync_committee_root().unpack(),
             next_committee_ssz_proof: self.next_committee_ssz_proof().unpack(),
             new_headers_mmr_proof: self.new_headers_mmr_proof().unpack(),
             updates: self.updates().unpack(),
+            sync_committee: self.sync_committee().unpack(),
         }
     }
 }
 impl_conversion_for_entity_unpack!(ProofUpdate);
 
+impl<'r> Unpack<core::LightClientBootstrap> for packed::LightClientBootstrapReader<'r> {
+    fn unpack(&self) -> core::LightClientBootstrap {
+        core::LightClientBootstrap {
+            header: self.header().unpack(),
+            current_sync_committee: self.current_sync_committee().unpack(),
+            current_sync_committee_branch: self.current_sync_committee_branch().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(LightClientBootstrap);
+
 impl<'r> Unpack<core::TransactionProof> for packed::TransactionProofReader<'r> {
     fn unpack(&self) -> core::TransactionProof {
         core::TransactionProof {
@@ -133,6 +230,207 @@ impl<'r> Unpack<core::TransactionPayload> for packed::TransactionPayloadReader<'
 }
 impl_conversion_for_entity_unpack!(TransactionPayload);
 
+impl<'r> Unpack<core::TransactionInBlockProof> for packed::TransactionInBlockProofReader<'r> {
+    fn unpack(&self) -> core::TransactionInBlockProof {
+        core::TransactionInBlockProof {
+            transaction_index: self.transaction_index().unpack(),
+            transaction_ssz_proof: self.transaction_ssz_proof().unpack(),
+            receipt_mpt_proof: self.receipt_mpt_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(TransactionInBlockProof);
+
+impl<'r> Unpack<core::TransactionInBlockProofVec> for packed::TransactionInBlockProofVecReader<'r> {
+    fn unpack(&self) -> core::TransactionInBlockProofVec {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(TransactionInBlockProofVec);
+
+impl<'r> Unpack<core::BlockTransactionsProof> for packed::BlockTransactionsProofReader<'r> {
+    fn unpack(&self) -> core::BlockTransactionsProof {
+        core::BlockTransactionsProof {
+            header: self.header().unpack(),
+            receipts_root: self.receipts_root().unpack(),
+            header_mmr_proof: self.header_mmr_proof().unpack(),
+            receipts_root_ssz_proof: self.receipts_root_ssz_proof().unpack(),
+            transactions: self.transactions().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(BlockTransactionsProof);
+
+impl<'r> Unpack<core::TransactionPayloadVec> for packed::TransactionPayloadVecReader<'r> {
+    fn unpack(&self) -> core::TransactionPayloadVec {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(TransactionPayloadVec);
+
+impl<'r> Unpack<core::ExecutionPayloadFieldProof> for packed::ExecutionPayloadFieldProofReader<'r> {
+    fn unpack(&self) -> core::ExecutionPayloadFieldProof {
+        core::ExecutionPayloadFieldProof {
+            header: self.header().unpack(),
+            field_index: self.field_index().into(),
+            field_root: self.field_root().unpack(),
+            header_mmr_proof: self.header_mmr_proof().unpack(),
+            field_ssz_proof: self.field_ssz_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ExecutionPayloadFieldProof);
+
+impl<'r> Unpack<core::StorageProof> for packed::StorageProofReader<'r> {
+    fn unpack(&self) -> core::StorageProof {
+        core::StorageProof {
+            key: self.key().unpack(),
+            value: self.value().unpack(),
+            proof: self.proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(StorageProof);
+
+impl<'r> Unpack<core::StorageProofVec> for packed::StorageProofVecReader<'r> {
+    fn unpack(&self) -> core::StorageProofVec {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(StorageProofVec);
+
+impl<'r> Unpack<core::StateProof> for packed::StateProofReader<'r> {
+    fn unpack(&self) -> core::StateProof {
+        core::StateProof {
+            header: self.header().unpack(),
+            state_root: self.state_root().unpack(),
+            header_mmr_proof: self.header_mmr_proof().unpack(),
+            state_root_ssz_proof: self.state_root_ssz_proof().unpack(),
+            address: self.address().unpack(),
+            nonce: self.nonce().unpack(),
+            balance: self.balance().unpack(),
+            storage_root: self.storage_root().unpack(),
+            code_hash: self.code_hash().unpack(),
+            account_mpt_proof: self.account_mpt_proof().unpack(),
+            storage_proofs: self.storage_proofs().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(StateProof);
+
+impl<'r> Unpack<core::Withdrawal> for packed::WithdrawalReader<'r> {
+    fn unpack(&self) -> core::Withdrawal {
+        core::Withdrawal {
+            index: self.index().unpack(),
+            validator_index: self.validator_index().unpack(),
+            address: self.address().unpack(),
+            amount: self.amount().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(Withdrawal);
+
+impl<'r> Unpack<core::WithdrawalProof> for packed::WithdrawalProofReader<'r> {
+    fn unpack(&self) -> core::WithdrawalProof {
+        core::WithdrawalProof {
+            header: self.header().unpack(),
+            withdrawal_index: self.withdrawal_index().unpack(),
+            withdrawal: self.withdrawal().unpack(),
+            header_mmr_proof: self.header_mmr_proof().unpack(),
+            withdrawal_ssz_proof: self.withdrawal_ssz_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(WithdrawalProof);
+
+impl<'r> Unpack<core::HistoricalBlockProof> for packed::HistoricalBlockProofReader<'r> {
+    fn unpack(&self) -> core::HistoricalBlockProof {
+        core::HistoricalBlockProof {
+            anchor_header: self.anchor_header().unpack(),
+            header: self.header().unpack(),
+            anchor_header_mmr_proof: self.anchor_header_mmr_proof().unpack(),
+            header_ssz_proof: self.header_ssz_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(HistoricalBlockProof);
+
+impl<'r> Unpack<core::HeadersWindowProof> for packed::HeadersWindowProofReader<'r> {
+    fn unpack(&self) -> core::HeadersWindowProof {
+        core::HeadersWindowProof {
+            digests: self
+                .digests()
+                .iter()
+                .map(|digest| digest.unpack())
+                .collect(),
+            digests_mmr_proof: self.digests_mmr_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(HeadersWindowProof);
+
+impl<'r> Unpack<core::ClientUpdate> for packed::ClientUpdateReader<'r> {
+    fn unpack(&self) -> core::ClientUpdate {
+        match self.to_enum() {
+            packed::ClientUpdateUnionReader::ProofUpdate(update) => {
+                core::ClientUpdate::ProofUpdate(update.unpack())
+            }
+            packed::ClientUpdateUnionReader::HeadersWindowProof(proof) => {
+                core::ClientUpdate::HeadersWindowProof(proof.unpack())
+            }
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ClientUpdate);
+
+impl<'r> Unpack<core::ForkConfig> for packed::ForkConfigReader<'r> {
+    fn unpack(&self) -> core::ForkConfig {
+        core::ForkConfig {
+            version: self.version().unpack(),
+            epoch: self.epoch().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ForkConfig);
+
+impl<'r> Unpack<core::ForkConfigVec> for packed::ForkConfigVecReader<'r> {
+    // The forks which aren't in the vector are not scheduled, and the forks which aren't known
+    // are dropped.
+    fn unpack(&self) -> core::ForkConfigVec {
+        let mut forks = [core::ForkConfig::UNSCHEDULED; Fork::SCHEDULED.len()];
+        for (fork, packed_fork) in forks.iter_mut().zip(self.iter()) {
+            *fork = packed_fork.unpack();
+        }
+        forks
+    }
+}
+impl_conversion_for_entity_unpack!(ForkConfigVec);
+
+impl<'r> Unpack<core::Preset> for packed::PresetReader<'r> {
+    fn unpack(&self) -> core::Preset {
+        core::Preset {
+            slots_per_epoch: self.slots_per_epoch().unpack(),
+            epochs_per_sync_committee_period: self.epochs_per_sync_committee_period().unpack(),
+            sync_committee_size: self.sync_committee_size().unpack(),
+            max_withdrawals_per_payload: self.max_withdrawals_per_payload().unpack(),
+            slots_per_historical_root: self.slots_per_historical_root().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(Preset);
+
+impl<'r> Unpack<core::ChainSpec> for packed::ChainSpecReader<'r> {
+    fn unpack(&self) -> core::ChainSpec {
+        core::ChainSpec {
+            genesis_validators_root: self.genesis_validators_root().unpack(),
+            genesis_fork_version: self.genesis_fork_version().unpack(),
+            preset: self.preset().unpack(),
+            forks: self.forks().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ChainSpec);
+
 impl<'r> Unpack<core::ClientInfo> for packed::ClientInfoReader<'r> {
     fn unpack(&self) -> core::ClientInfo {
         core::ClientInfo {
@@ -151,6 +449,9 @@ impl<'r> Unpack<core::Client> for packed::ClientReader<'r> {
             maximal_slot: self.maximal_slot().unpack(),
             tip_valid_header_root: self.tip_valid_header_root().unpack(),
             headers_mmr_root: self.headers_mmr_root().unpack(),
+            sync_committee_period: self.sync_committee_period().unpack(),
+            current_sync_committee_root: self.current_sync_committee_root().unpack(),
+            next_sync_committee_root: self.next_sync_committee_root().unpack(),
         }
     }
 }
@@ -161,6 +462,10 @@ impl<'r> Unpack<core::ClientTypeArgs> for packed::ClientTypeArgsReader<'r> {
         core::ClientTypeArgs {
             type_id: self.type_id().unpack(),
             cells_count: self.cells_count().into(),
+            chain_spec: self.chain_spec().unpack(),
+            trusted_block_root: self.trusted_block_root().unpack(),
+            headers_window_size: self.headers_window_size().unpack(),
+            owner_lock_hash: self.owner_lock_hash().unpack(),
         }
     }
 }
@@ -6,10 +6,20 @@ use ssz_derive::Encode;
 use tree_hash::Hash256;
 use tree_hash_derive::TreeHash;
 
+use crate::consensus_specs::helpers::Fork;
+
 pub type Uint64 = u64;
 pub type Hash = Hash256;
 pub type Bytes = Vec<u8>;
 
+pub type Address = [u8; 20];
+pub type Uint256 = [u8; 32];
+
+pub type BlsPubkey = [u8; 48];
+pub type BlsSignature = [u8; 96];
+
+pub type BlsPubkeyVec = Vec<BlsPubkey>;
+
 pub type SszProof = Vec<Hash>;
 pub type MptProof = Vec<Bytes>;
 
@@ -29,11 +39,27 @@ pub struct Header {
     pub body_root: Hash,
 }
 
+pub type HeaderVec = Vec<Header>;
+
+#[derive(Clone)]
+pub struct SyncCommittee {
+    pub pubkeys: BlsPubkeyVec,
+    pub aggregate_pubkey: BlsPubkey,
+}
+
+#[derive(Clone)]
+pub struct SyncAggregate {
+    pub sync_committee_bits: Bytes,
+    pub sync_committee_signature: BlsSignature,
+}
+
 #[derive(Clone)]
 pub struct FinalityUpdate {
     pub attested_header: Header,
     pub finalized_header: Header,
     pub finality_branch: SszProof,
+    pub sync_aggregate: SyncAggregate,
+    pub signature_slot: Uint64,
 }
 
 pub type FinalityUpdateVec = Vec<FinalityUpdate>;
@@ -41,9 +67,18 @@ pub type FinalityUpdateVec = Vec<FinalityUpdate>;
 #[derive(Clone)]
 pub struct ProofUpdate {
     pub new_headers_mmr_root: HeaderDigest,
+    pub next_sync_committee_root: Hash,
     pub next_committee_ssz_proof: SszProof,
     pub new_headers_mmr_proof: MmrProof,
     pub updates: FinalityUpdateVec,
+    pub sync_committee: SyncCommittee,
+}
+
+#[derive(Clone)]
+pub struct LightClientBootstrap {
+    pub header: Header,
+    pub current_sync_committee: SyncCommittee,
+    pub current_sync_committee_branch: SszProof,
 }
 
 #[derive(Clone)]
@@ -63,6 +98,124 @@ pub struct TransactionPayload {
     pub receipt: Bytes,
 }
 
+#[derive(Clone)]
+pub struct TransactionInBlockProof {
+    pub transaction_index: Uint64,
+    pub transaction_ssz_proof: SszProof,
+    pub receipt_mpt_proof: MptProof,
+}
+
+pub type TransactionInBlockProofVec = Vec<TransactionInBlockProof>;
+
+#[derive(Clone)]
+pub struct BlockTransactionsProof {
+    pub header: Header,
+    pub receipts_root: Hash,
+    pub header_mmr_proof: MmrProof,
+    pub receipts_root_ssz_proof: SszProof,
+    pub transactions: TransactionInBlockProofVec,
+}
+
+pub type TransactionPayloadVec = Vec<TransactionPayload>;
+
+#[derive(Clone)]
+pub struct ExecutionPayloadFieldProof {
+    pub header: Header,
+    pub field_index: u8,
+    pub field_root: Hash,
+    pub header_mmr_proof: MmrProof,
+    pub field_ssz_proof: SszProof,
+}
+
+#[derive(Clone)]
+pub struct StorageProof {
+    pub key: Hash,
+    pub value: Uint256,
+    pub proof: MptProof,
+}
+
+pub type StorageProofVec = Vec<StorageProof>;
+
+#[derive(Clone)]
+pub struct StateProof {
+    pub header: Header,
+    pub state_root: Hash,
+    pub header_mmr_proof: MmrProof,
+    pub state_root_ssz_proof: SszProof,
+    pub address: Address,
+    pub nonce: Uint64,
+    pub balance: Uint256,
+    pub storage_root: Hash,
+    pub code_hash: Hash,
+    pub account_mpt_proof: MptProof,
+    pub storage_proofs: StorageProofVec,
+}
+
+#[derive(Clone)]
+pub struct Withdrawal {
+    pub index: Uint64,
+    pub validator_index: Uint64,
+    pub address: Address,
+    pub amount: Uint64,
+}
+
+#[derive(Clone)]
+pub struct WithdrawalProof {
+    pub header: Header,
+    pub withdrawal_index: Uint64,
+    pub withdrawal: Withdrawal,
+    pub header_mmr_proof: MmrProof,
+    pub withdrawal_ssz_proof: SszProof,
+}
+
+#[derive(Clone)]
+pub struct HistoricalBlockProof {
+    pub anchor_header: Header,
+    pub header: Header,
+    pub anchor_header_mmr_proof: MmrProof,
+    pub header_ssz_proof: SszProof,
+}
+
+#[derive(Clone)]
+pub struct HeadersWindowProof {
+    pub digests: Vec<HeaderDigest>,
+    pub digests_mmr_proof: MmrProof,
+}
+
+#[derive(Clone)]
+pub enum ClientUpdate {
+    ProofUpdate(ProofUpdate),
+    HeadersWindowProof(HeadersWindowProof),
+}
+
+pub type ForkVersion = [u8; 4];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ForkConfig {
+    pub version: ForkVersion,
+    pub epoch: Uint64,
+}
+
+// The configurations of the scheduled forks in order, only the known forks could be verified.
+pub type ForkConfigVec = [ForkConfig; Fork::SCHEDULED.len()];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Preset {
+    pub slots_per_epoch: Uint64,
+    pub epochs_per_sync_committee_period: Uint64,
+    pub sync_committee_size: Uint64,
+    pub max_withdrawals_per_payload: Uint64,
+    pub slots_per_historical_root: Uint64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ChainSpec {
+    pub genesis_validators_root: Hash,
+    pub genesis_fork_version: ForkVersion,
+    pub preset: Preset,
+    pub forks: ForkConfigVec,
+}
+
 #[derive(Clone)]
 pub struct ClientInfo {
     pub last_id: u8,
@@ -76,12 +229,19 @@ pub struct Client {
     pub maximal_slot: Uint64,
     pub tip_valid_header_root: Hash,
     pub headers_mmr_root: HeaderDigest,
+    pub sync_committee_period: Uint64,
+    pub current_sync_committee_root: Hash,
+    pub next_sync_committee_root: Hash,
 }
 
 #[derive(Clone)]
 pub struct ClientTypeArgs {
     pub type_id: Hash,
     pub cells_count: u8,
+    pub chain_spec: ChainSpec,
+    pub trusted_block_root: Hash,
+    pub headers_window_size: Uint64,
+    pub owner_lock_hash: Hash,
 }
 
 #[cfg(feature = "std")]
@@ -121,8 +281,12 @@ impl fmt::Display for Client {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "{{ id: {}, slots: [{}, {}], tip: {:#x} }}",
-            self.id, self.minimal_slot, self.maximal_slot, self.tip_valid_header_root
+            "{{ id: {}, slots: [{}, {}], tip: {:#x}, period: {} }}",
+            self.id,
+            self.minimal_slot,
+            self.maximal_slot,
+            self.tip_valid_header_root,
+            self.sync_committee_period
         )
     }
 }
@@ -4,10 +4,11 @@ pub mod prelude;
 
 mod conversion;
 
-use ::core::result::Result;
-use alloc::{vec, vec::Vec};
+use ::core::{iter, result::Result};
+use alloc::vec::Vec;
 
-use ckb_mmr::{leaf_index_to_mmr_size, leaf_index_to_pos, Error as MMRError};
+use ckb_mmr::{leaf_index_to_mmr_size, leaf_index_to_pos, util::MemStore, Error as MMRError};
+use eth2_hashing::hash32_concat;
 use rlp::encode;
 use ssz_types::{typenum, VariableList};
 use tree_hash::{Hash256, TreeHash as _};
@@ -16,27 +17,229 @@ pub use generated::packed;
 
 use self::prelude::*;
 use crate::{
-    consensus_specs as specs,
-    error::{ProofUpdateError, TxVerificationError},
+    bls,
+    consensus_specs::{self as specs, helpers::Fork},
+    error::{
+        BootstrapError, ClientCellsError, FieldVerificationError, HeaderVerificationError,
+        HeadersWindowError, HistoricalBlockVerificationError, ProofUpdateError,
+        StateVerificationError, TxVerificationError, WithdrawalVerificationError,
+    },
     mmr, ssz, trie,
 };
 
 impl core::Client {
-    pub fn new_from_packed_proof_update(
-        packed_proof_update: packed::ProofUpdateReader,
-    ) -> Result<Self, ProofUpdateError> {
-        Self::new_or_update_with_packed_proof_update(None, packed_proof_update)
+    /// Creates a new client from a trusted block root, which is usually a weak subjectivity
+    /// checkpoint.
+    ///
+    /// See [`initialize_light_client_store`].
+    ///
+    /// [`initialize_light_client_store`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#initialize_light_client_store
+    pub fn new_from_bootstrap(
+        spec: &core::ChainSpec,
+        trusted_block_root: core::Hash,
+        packed_bootstrap: packed::LightClientBootstrapReader,
+    ) -> Result<Self, BootstrapError> {
+        let header = packed_bootstrap.header().unpack().calc_cache();
+        if header.root != trusted_block_root {
+            error!(
+                "header {header} isn't matched with the trusted block root \
+                {trusted_block_root:#x}"
+            );
+            return Err(BootstrapError::TrustedBlockRoot);
+        }
+
+        let current_sync_committee_root = packed_bootstrap.current_sync_committee().calc_root(spec);
+        let current_sync_committee_branch: core::SszProof =
+            packed_bootstrap.current_sync_committee_branch().unpack();
+        let (current_sync_committee_index, current_sync_committee_depth) =
+            match spec.compute_fork_at_slot(header.inner.slot) {
+                Fork::Fulu => (
+                    specs::fulu::CURRENT_SYNC_COMMITTEE_INDEX,
+                    specs::fulu::CURRENT_SYNC_COMMITTEE_DEPTH,
+                ),
+                Fork::Electra => (
+                    specs::electra::CURRENT_SYNC_COMMITTEE_INDEX,
+                    specs::electra::CURRENT_SYNC_COMMITTEE_DEPTH,
+                ),
+                _ => (
+                    specs::altair::CURRENT_SYNC_COMMITTEE_INDEX,
+                    specs::altair::CURRENT_SYNC_COMMITTEE_DEPTH,
+                ),
+            };
+        if current_sync_committee_branch.len() != current_sync_committee_depth as usize
+            || !ssz::verify_merkle_proof(
+                header.inner.state_root,
+                current_sync_committee_root,
+                &current_sync_committee_branch,
+                current_sync_committee_index,
+            )
+        {
+            error!(
+                "failed to verify the current sync committee {current_sync_committee_root:#x} \
+                in header {header:#}"
+            );
+            return Err(BootstrapError::CurrentSyncCommitteeSszProof);
+        }
+
+        // The MMR only has one leaf, so its root is the digest of the leaf.
+        let headers_mmr_root = header.digest().unpack();
+        let new_client = Self {
+            id: 0,
+            minimal_slot: header.inner.slot,
+            maximal_slot: header.inner.slot,
+            tip_valid_header_root: header.root,
+            headers_mmr_root,
+            sync_committee_period: spec.compute_sync_committee_period_at_slot(header.inner.slot),
+            current_sync_committee_root,
+            next_sync_committee_root: Hash256::zero(),
+        };
+
+        info!("new client from bootstrap: {new_client}");
+
+        Ok(new_client)
     }
 
-    pub fn try_apply_packed_proof_update(
+    /// Applies the proof update to the client, which is the latest one in the client cells.
+    ///
+    /// The proof update should contain at least `minimal_updates_count` non-empty finalized
+    /// headers, to avoid the client cells being replaced too frequently.
+    /// The empty slots are not counted, so they couldn't be used to pad a proof update.
+    pub fn try_apply_packed_proof_update_with_info(
         &self,
+        spec: &core::ChainSpec,
+        info: &core::ClientInfo,
         packed_proof_update: packed::ProofUpdateReader,
     ) -> Result<Self, ProofUpdateError> {
-        Self::new_or_update_with_packed_proof_update(Some(self), packed_proof_update)
+        let valid_headers_count = packed_proof_update
+            .updates()
+            .iter()
+            .filter(|update| {
+                let header: core::Header = update.finalized_header().unpack();
+                !header.is_empty()
+            })
+            .count();
+        if valid_headers_count < usize::from(info.minimal_updates_count) {
+            error!(
+                "the count of non-empty finalized headers {valid_headers_count} is less than {}",
+                info.minimal_updates_count
+            );
+            return Err(ProofUpdateError::TooFewUpdates);
+        }
+        self.try_apply_packed_proof_update(spec, packed_proof_update)
+    }
+
+    /// Slides the headers window of the client, which is the latest one in the client cells.
+    ///
+    /// The window size is committed in the type args of the client cells, so it couldn't be
+    /// chosen by the witness.
+    /// At least `minimal_updates_count` slots should be dropped, so the client cells couldn't be
+    /// replaced by slides more frequently than by proof updates.
+    pub fn try_slide_headers_window_with_info(
+        &self,
+        window_size: u64,
+        info: &core::ClientInfo,
+        packed_proof: packed::HeadersWindowProofReader,
+    ) -> Result<Self, HeadersWindowError> {
+        let headers_count = self.maximal_slot - self.minimal_slot + 1;
+        let dropped_count = headers_count.saturating_sub(window_size);
+        if dropped_count < u64::from(info.minimal_updates_count) {
+            error!(
+                "the count of dropped slots {dropped_count} is less than {}",
+                info.minimal_updates_count
+            );
+            return Err(HeadersWindowError::TooFewDroppedSlots);
+        }
+        self.try_slide_headers_window(window_size, packed_proof)
     }
 
-    fn new_or_update_with_packed_proof_update(
-        prev_client_opt: Option<&Self>,
+    /// Drops the headers before the last `window_size` slots, and starts a new headers MMR which
+    /// only contains the headers in the window.
+    ///
+    /// The digests in the proof are the leaves of the new headers MMR, and they are proved to be
+    /// the last leaves of the current headers MMR.
+    /// The tip valid header and the sync committees are not changed, so the new client continues
+    /// the current one, while the size of the headers MMR and its proofs stop growing.
+    pub fn try_slide_headers_window(
+        &self,
+        window_size: u64,
+        packed_proof: packed::HeadersWindowProofReader,
+    ) -> Result<Self, HeadersWindowError> {
+        let headers_count = self.maximal_slot - self.minimal_slot + 1;
+        if window_size == 0 || window_size >= headers_count {
+            error!(
+                "the window size {window_size} should be in [1, {headers_count}), \
+                client: [{}, {}]",
+                self.minimal_slot, self.maximal_slot
+            );
+            return Err(HeadersWindowError::WindowSize);
+        }
+        let digests = packed_proof.digests();
+        if digests.len() as u64 != window_size {
+            error!(
+                "the count of digests {} should be the window size {window_size}",
+                digests.len()
+            );
+            return Err(HeadersWindowError::DigestsCount);
+        }
+        let minimal_slot = self.maximal_slot + 1 - window_size;
+
+        let proof: mmr::MMRProof = {
+            let max_index = self.maximal_slot - self.minimal_slot;
+            let mmr_size = leaf_index_to_mmr_size(max_index);
+            let proof = packed_proof
+                .digests_mmr_proof()
+                .iter()
+                .map(|r| r.to_entity())
+                .collect::<Vec<_>>();
+            mmr::MMRProof::new(mmr_size, proof)
+        };
+        let first_index = minimal_slot - self.minimal_slot;
+        let digests_with_positions = digests
+            .iter()
+            .zip(first_index..)
+            .map(|(digest, index)| (leaf_index_to_pos(index), digest.to_entity()))
+            .collect::<Vec<_>>();
+        let result = proof
+            .verify(self.headers_mmr_root.pack(), digests_with_positions)
+            .map_err(|_| HeadersWindowError::Other)?;
+        if !result {
+            error!(
+                "failed to verify MMR proof for the headers in [{minimal_slot}, {}]",
+                self.maximal_slot
+            );
+            return Err(HeadersWindowError::HeadersMmrProof);
+        }
+
+        let headers_mmr_root = {
+            let store = MemStore::default();
+            let mut mmr = mmr::ClientRootMMR::new(0, &store);
+            for digest in digests.iter() {
+                mmr.push(digest.to_entity())
+                    .map_err(|_| HeadersWindowError::Other)?;
+            }
+            mmr.get_root().map_err(|_| HeadersWindowError::Other)?
+        };
+
+        let new_client = Self {
+            minimal_slot,
+            headers_mmr_root: headers_mmr_root.unpack(),
+            ..self.clone()
+        };
+
+        info!("new client: {new_client}");
+
+        Ok(new_client)
+    }
+
+    /// Applies the proof update to the client.
+    ///
+    /// A client could only be created by [`Client::new_from_bootstrap`], so all sync committees
+    /// which sign the proof updates are anchored to the trusted block root.
+    ///
+    /// The last new header should be non-empty, it's the new tip valid header which is attested.
+    pub fn try_apply_packed_proof_update(
+        &self,
+        spec: &core::ChainSpec,
         packed_proof_update: packed::ProofUpdateReader,
     ) -> Result<Self, ProofUpdateError> {
         let updates = packed_proof_update.updates();
@@ -55,57 +258,42 @@ impl core::Client {
         let minimal_slot;
         let mut header_mmr_index;
 
+        let first_update = updates_iter.next().unwrap();
         let mut curr_cached_header = {
-            let header: core::Header = updates_iter.next().unwrap().finalized_header().unpack();
+            let header: core::Header = first_update.finalized_header().unpack();
             header.calc_cache()
         };
         let mut prev_cached_header: mmr::HeaderWithCache;
         let mut curr_tip_valid_header_root: Hash256;
+        let mut last_update = first_update;
 
         {
             info!("first header: {curr_cached_header}");
+            info!("update client with updates (len: {updates_len}), client: {self}");
 
-            if let Some(client) = prev_client_opt {
-                info!("update client with updates (len: {updates_len}), client: {client}");
-
-                // Check Old Tip Header (with the first header)
-                if curr_cached_header.inner.slot != client.maximal_slot + 1 {
-                    error!(
-                        "first header isn't continuous with client on slot, \
-                        client: {client}, header: {curr_cached_header}"
-                    );
-                    return Err(ProofUpdateError::FirstHeaderSlot);
-                }
+            // Check Old Tip Header (with the first header)
+            if curr_cached_header.inner.slot != self.maximal_slot + 1 {
+                error!(
+                    "first header isn't continuous with client on slot, \
+                    client: {self}, header: {curr_cached_header}"
+                );
+                return Err(ProofUpdateError::FirstHeaderSlot);
+            }
 
-                curr_tip_valid_header_root = if curr_cached_header.inner.is_empty() {
-                    client.tip_valid_header_root
-                } else {
-                    if curr_cached_header.inner.parent_root != client.tip_valid_header_root {
-                        error!(
-                            "first header isn't continuous with client on root, \
-                            client: {client}, header: {curr_cached_header}"
-                        );
-                        return Err(ProofUpdateError::FirstHeaderParentRoot);
-                    }
-                    curr_cached_header.root
-                };
-                minimal_slot = client.minimal_slot;
-                header_mmr_index = client.maximal_slot - client.minimal_slot + 1;
+            curr_tip_valid_header_root = if curr_cached_header.inner.is_empty() {
+                self.tip_valid_header_root
             } else {
-                info!("create new client with updates (len: {updates_len})");
-
-                curr_tip_valid_header_root = if curr_cached_header.inner.is_empty() {
+                if curr_cached_header.inner.parent_root != self.tip_valid_header_root {
                     error!(
-                        "first header is empty when create new client, \
-                        header: {curr_cached_header}"
+                        "first header isn't continuous with client on root, \
+                        client: {self}, header: {curr_cached_header}"
                     );
-                    return Err(ProofUpdateError::FirstHeaderForCreate);
-                } else {
-                    curr_cached_header.root
-                };
-                minimal_slot = curr_cached_header.inner.slot;
-                header_mmr_index = 0;
-            }
+                    return Err(ProofUpdateError::FirstHeaderParentRoot);
+                }
+                curr_cached_header.root
+            };
+            minimal_slot = self.minimal_slot;
+            header_mmr_index = self.maximal_slot - self.minimal_slot + 1;
             prev_cached_header = curr_cached_header;
         }
 
@@ -142,8 +330,7 @@ impl core::Client {
                     }
                     curr_tip_valid_header_root = curr_cached_header.root;
                 }
-
-                // TODO verify more, such as BLS
+                last_update = update;
 
                 let position = leaf_index_to_pos(header_mmr_index);
                 trace!("previous header in MMR on index {header_mmr_index}, position {position}");
@@ -156,6 +343,16 @@ impl core::Client {
             }
         }
 
+        // The last header should be the new tip valid header, which is attested.
+        //
+        // Otherwise, the empty headers after the tip valid header are not attested, so a slot which
+        // has a block could be marked as empty, then the client couldn't be updated anymore.
+        if prev_cached_header.inner.is_empty() {
+            error!("the last header {prev_cached_header} is empty, so it isn't attested");
+            return Err(ProofUpdateError::UnattestedTipHeader);
+        }
+        let tip_update = last_update;
+
         let maximal_slot = prev_cached_header.inner.slot;
 
         // Handle the last update
@@ -167,6 +364,20 @@ impl core::Client {
             digests_with_positions.push((position, digest));
         }
 
+        // Check Attestation
+        //
+        // Only the finalized header of the new tip valid header is required to be attested,
+        // since all other new headers are its ancestors, which are checked by parent roots.
+        let trusted_sync_committee_root = self.trusted_sync_committee_root(spec, tip_update)?;
+        let (sync_committee_period, current_sync_committee_root, next_sync_committee_root) =
+            self.apply_sync_committees(spec, tip_update, packed_proof_update)?;
+        Self::verify_finality_update(
+            spec,
+            tip_update,
+            packed_proof_update.sync_committee(),
+            trusted_sync_committee_root,
+        )?;
+
         // Check MMR Root
         {
             let proof: mmr::MMRProof = {
@@ -192,13 +403,15 @@ impl core::Client {
         }
 
         let headers_mmr_root = packed_proof_update.new_headers_mmr_root().unpack();
-        let id = prev_client_opt.map(|client| client.id).unwrap_or(0);
         let new_client = Self {
-            id,
+            id: self.id,
             minimal_slot,
             maximal_slot,
             tip_valid_header_root: curr_tip_valid_header_root,
             headers_mmr_root,
+            sync_committee_period,
+            current_sync_committee_root,
+            next_sync_committee_root,
         };
 
         info!("new client: {new_client}");
@@ -206,165 +419,1205 @@ impl core::Client {
         Ok(new_client)
     }
 
+    // Returns the root of the sync committee which should sign the tip update.
+    //
+    // The sync committee should be the current sync committee, or the next sync committee if it's
+    // known.
+    fn trusted_sync_committee_root(
+        &self,
+        spec: &core::ChainSpec,
+        tip_update: packed::FinalityUpdateReader,
+    ) -> Result<Hash256, ProofUpdateError> {
+        let signature_period =
+            spec.compute_sync_committee_period_at_slot(tip_update.signature_slot().unpack());
+        let store_period = self.sync_committee_period;
+        let is_next_known = !self.next_sync_committee_root.is_zero();
+        if signature_period == store_period {
+            Ok(self.current_sync_committee_root)
+        } else if signature_period == store_period + 1 && is_next_known {
+            Ok(self.next_sync_committee_root)
+        } else {
+            error!(
+                "no sync committee for signature period {signature_period}, \
+                client period: {store_period}, next sync committee known: {is_next_known}"
+            );
+            Err(ProofUpdateError::UnmatchedSyncCommittee)
+        }
+    }
+
+    // Returns the sync committee period, the roots of the current sync committee and the next
+    // sync committee, after the tip update is applied.
+    //
+    // The sync committees will be rotated when the tip valid header enters the next sync
+    // committee period.
+    fn apply_sync_committees(
+        &self,
+        spec: &core::ChainSpec,
+        tip_update: packed::FinalityUpdateReader,
+        packed_proof_update: packed::ProofUpdateReader,
+    ) -> Result<(u64, Hash256, Hash256), ProofUpdateError> {
+        let finalized_period = spec
+            .compute_sync_committee_period_at_slot(tip_update.finalized_header().slot().unpack());
+
+        let store_period = self.sync_committee_period;
+        let is_next_known = !self.next_sync_committee_root.is_zero();
+        let (period, current_root, mut next_root) = if finalized_period == store_period {
+            (
+                store_period,
+                self.current_sync_committee_root,
+                self.next_sync_committee_root,
+            )
+        } else if finalized_period == store_period + 1 && is_next_known {
+            info!("rotate sync committees from period {store_period} to {finalized_period}");
+            (
+                finalized_period,
+                self.next_sync_committee_root,
+                Hash256::zero(),
+            )
+        } else {
+            error!(
+                "failed to rotate sync committees to period {finalized_period}, \
+                client period: {store_period}, next sync committee known: {is_next_known}"
+            );
+            return Err(ProofUpdateError::UnmatchedSyncCommittee);
+        };
+
+        let next_committee_ssz_proof: core::SszProof =
+            packed_proof_update.next_committee_ssz_proof().unpack();
+        if !next_committee_ssz_proof.is_empty() {
+            let attested_header: core::Header = tip_update.attested_header().unpack();
+            let attested_period = spec.compute_sync_committee_period_at_slot(attested_header.slot);
+            let next_sync_committee_root = packed_proof_update.next_sync_committee_root().unpack();
+            let (next_sync_committee_index, next_sync_committee_depth) =
+                match spec.compute_fork_at_slot(attested_header.slot) {
+                    Fork::Fulu => (
+                        specs::fulu::NEXT_SYNC_COMMITTEE_INDEX,
+                        specs::fulu::NEXT_SYNC_COMMITTEE_DEPTH,
+                    ),
+                    Fork::Electra => (
+                        specs::electra::NEXT_SYNC_COMMITTEE_INDEX,
+                        specs::electra::NEXT_SYNC_COMMITTEE_DEPTH,
+                    ),
+                    _ => (
+                        specs::altair::NEXT_SYNC_COMMITTEE_INDEX,
+                        specs::altair::NEXT_SYNC_COMMITTEE_DEPTH,
+                    ),
+                };
+            if attested_period != period
+                || next_committee_ssz_proof.len() != next_sync_committee_depth as usize
+                || !ssz::verify_merkle_proof(
+                    attested_header.state_root,
+                    next_sync_committee_root,
+                    &next_committee_ssz_proof,
+                    next_sync_committee_index,
+                )
+            {
+                error!(
+                    "failed to verify the next sync committee {next_sync_committee_root:#x} \
+                    (attested period: {attested_period}, period: {period})"
+                );
+                return Err(ProofUpdateError::NextSyncCommitteeSszProof);
+            }
+            if !next_root.is_zero() && next_root != next_sync_committee_root {
+                error!(
+                    "next sync committee {next_sync_committee_root:#x} isn't matched, \
+                    expect {next_root:#x}"
+                );
+                return Err(ProofUpdateError::UnmatchedSyncCommittee);
+            }
+            next_root = next_sync_committee_root;
+        }
+
+        Ok((period, current_root, next_root))
+    }
+
+    fn verify_finality_update(
+        spec: &core::ChainSpec,
+        update: packed::FinalityUpdateReader,
+        sync_committee: packed::SyncCommitteeReader,
+        trusted_sync_committee_root: Hash256,
+    ) -> Result<(), ProofUpdateError> {
+        // The sync committee is supplied by the updater, so it should be anchored to the trusted
+        // root before its signature is checked.
+        let sync_committee_root = sync_committee.calc_root(spec);
+        if sync_committee_root != trusted_sync_committee_root {
+            error!(
+                "sync committee {sync_committee_root:#x} isn't matched, \
+                expect {trusted_sync_committee_root:#x}"
+            );
+            return Err(ProofUpdateError::UnmatchedSyncCommittee);
+        }
+
+        let attested_header = update.attested_header().unpack().calc_cache();
+        if attested_header.is_empty() {
+            error!(
+                "the tip valid header isn't attested, finalized header: {}",
+                update.finalized_header().unpack()
+            );
+            return Err(ProofUpdateError::UnattestedTipHeader);
+        }
+
+        let finalized_slot: u64 = update.finalized_header().slot().unpack();
+        let signature_slot: u64 = update.signature_slot().unpack();
+        if signature_slot <= attested_header.inner.slot
+            || attested_header.inner.slot < finalized_slot
+        {
+            error!(
+                "slots in finality update are not ordered, signature slot: {signature_slot}, \
+                attested header: {attested_header}, finalized slot: {finalized_slot}"
+            );
+            return Err(ProofUpdateError::UnorderedSlots);
+        }
+
+        // Check the finalized header is the finalized checkpoint in the attested state.
+        {
+            let finalized_header = update.finalized_header().unpack().calc_cache();
+            let finality_branch: core::SszProof = update.finality_branch().unpack();
+            let (finalized_root_index, finalized_root_depth) =
+                match spec.compute_fork_at_slot(attested_header.inner.slot) {
+                    Fork::Fulu => (
+                        specs::fulu::FINALIZED_ROOT_INDEX,
+                        specs::fulu::FINALIZED_ROOT_DEPTH,
+                    ),
+                    Fork::Electra => (
+                        specs::electra::FINALIZED_ROOT_INDEX,
+                        specs::electra::FINALIZED_ROOT_DEPTH,
+                    ),
+                    _ => (
+                        specs::altair::FINALIZED_ROOT_INDEX,
+                        specs::altair::FINALIZED_ROOT_DEPTH,
+                    ),
+                };
+            if finality_branch.len() != finalized_root_depth as usize
+                || !ssz::verify_merkle_proof(
+                    attested_header.inner.state_root,
+                    finalized_header.root,
+                    &finality_branch,
+                    finalized_root_index,
+                )
+            {
+                error!(
+                    "failed to verify the finality branch, \
+                    attested header: {attested_header:#}, finalized header: {finalized_header}"
+                );
+                return Err(ProofUpdateError::FinalityBranch);
+            }
+        }
+
+        let sync_aggregate = update.sync_aggregate();
+        let sync_committee_bits = sync_aggregate.sync_committee_bits().raw_data();
+        let pubkeys = sync_committee.pubkeys();
+        let sync_committee_size = spec.preset.sync_committee_size as usize;
+        if sync_committee_bits.len() * 8 != sync_committee_size
+            || pubkeys.len() != sync_committee_size
+        {
+            error!(
+                "the size of sync committee is incorrect, bits: {}, pubkeys: {}",
+                sync_committee_bits.len() * 8,
+                pubkeys.len()
+            );
+            return Err(ProofUpdateError::SyncCommitteeParticipation);
+        }
+
+        let participant_pubkeys = pubkeys
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| sync_committee_bits[index / 8] & (1 << (index % 8)) != 0)
+            .map(|(_, pubkey)| pubkey.raw_data())
+            .collect::<Vec<_>>();
+        // At least 2/3 of the sync committee should participate.
+        if participant_pubkeys.len() * 3 < sync_committee_size * 2 {
+            error!(
+                "participants of sync committee are not enough, count: {}",
+                participant_pubkeys.len()
+            );
+            return Err(ProofUpdateError::SyncCommitteeParticipation);
+        }
+
+        let signing_root = {
+            // The signature is signed at the previous slot of the signature slot.
+            let fork_version_epoch = spec.compute_epoch_at_slot(signature_slot - 1);
+            let fork_version = spec.compute_fork_version(fork_version_epoch);
+            let domain = specs::helpers::compute_domain(
+                specs::altair::DOMAIN_SYNC_COMMITTEE,
+                fork_version,
+                spec.genesis_validators_root,
+            );
+            specs::helpers::compute_signing_root(attested_header.root, domain)
+        };
+        let signature = sync_aggregate.sync_committee_signature().raw_data();
+        if !bls::fast_aggregate_verify(&participant_pubkeys, signing_root.as_bytes(), signature) {
+            error!(
+                "failed to verify the sync aggregate signature, \
+                attested header: {attested_header}, signing root: {signing_root:#x}"
+            );
+            return Err(ProofUpdateError::SyncAggregateSignature);
+        }
+
+        debug!(
+            "passed: verify sync aggregate for attested header {attested_header} \
+            (participants: {})",
+            participant_pubkeys.len()
+        );
+        Ok(())
+    }
+
     pub fn verify_packed_transaction_proof(
         &self,
         tx_proof: packed::TransactionProofReader,
     ) -> Result<(), TxVerificationError> {
-        let header_slot = tx_proof.header().slot().unpack();
-        if self.minimal_slot > header_slot || self.maximal_slot < header_slot {
-            log_if_enabled!(|Warn| {
-                let tx_proof = tx_proof.unpack();
-                let header = tx_proof.header.calc_cache();
+        self.verify_header_in_client(
+            tx_proof.header(),
+            tx_proof.header_mmr_proof(),
+            "transaction proof",
+        )
+        .map_err(Into::into)
+    }
+
+    /// Verifies the proofs for the block of several transactions, with only one MMR proof.
+    ///
+    /// The transactions themselves should be verified by
+    /// [`core::BlockTransactionsProof::verify_packed_payloads`].
+    pub fn verify_packed_block_transactions_proof(
+        &self,
+        block_proof: packed::BlockTransactionsProofReader,
+    ) -> Result<(), TxVerificationError> {
+        self.verify_header_in_client(
+            block_proof.header(),
+            block_proof.header_mmr_proof(),
+            "block transactions proof",
+        )
+        .map_err(Into::into)
+    }
+
+    /// Verifies several headers with one MMR proof.
+    ///
+    /// The headers should be sorted by slots, without duplicates.
+    pub fn verify_packed_headers(
+        &self,
+        headers: packed::HeaderVecReader,
+        headers_mmr_proof: packed::MmrProofReader,
+    ) -> Result<(), HeaderVerificationError> {
+        if headers.is_empty() {
+            warn!("failed: verify headers since no headers");
+            return Err(HeaderVerificationError::EmptyHeaders);
+        }
+        let mut prev_slot_opt = None;
+        for header in headers.iter() {
+            let header_slot = header.slot().unpack();
+            if self.minimal_slot > header_slot || self.maximal_slot < header_slot {
                 warn!(
-                    "failed: verify slots for header {:#x}, for its {}-th transaction \
-                    (client: [{}, {}], header-slot: {header_slot})",
-                    header.root, tx_proof.transaction_index, self.minimal_slot, self.maximal_slot
+                    "failed: verify slots for header#{header_slot} \
+                    (client: [{}, {}])",
+                    self.minimal_slot, self.maximal_slot
                 );
-            });
-            return Err(TxVerificationError::Unsynchronized);
+                return Err(HeaderVerificationError::Unsynchronized);
+            }
+            if let Some(prev_slot) = prev_slot_opt {
+                if prev_slot >= header_slot {
+                    warn!(
+                        "failed: verify headers since header#{header_slot} \
+                        isn't after header#{prev_slot}"
+                    );
+                    return Err(HeaderVerificationError::UnorderedHeaders);
+                }
+            }
+            prev_slot_opt = Some(header_slot);
         }
         let result = self
-            .verify_single_header(tx_proof.header(), tx_proof.header_mmr_proof())
-            .map_err(|_| TxVerificationError::Other)?;
+            .verify_headers(headers.iter(), headers_mmr_proof)
+            .map_err(|_| HeaderVerificationError::Other)?;
         if !result {
-            log_if_enabled!(|Warn| {
-                let tx_proof = tx_proof.unpack();
-                let header = tx_proof.header.calc_cache();
-                warn!(
-                    "failed: verify MMR proof for header {:#x}, for its {}-th transaction",
-                    header.root, tx_proof.transaction_index
-                );
-            });
-            Err(TxVerificationError::HeaderMmrProof)
+            warn!("failed: verify MMR proof for {} headers", headers.len());
+            Err(HeaderVerificationError::HeaderMmrProof)
         } else {
-            log_if_enabled!(|Debug| {
-                let tx_proof = tx_proof.unpack();
-                let header = tx_proof.header.calc_cache();
-                debug!(
-                    "passed: verify MMR proof for header {:#x}, for its {}-th transaction",
-                    header.root, tx_proof.transaction_index
-                );
-            });
+            debug!("passed: verify MMR proof for {} headers", headers.len());
             Ok(())
         }
     }
 
-    fn verify_single_header(
+    /// Checks whether the header is in the headers MMR of the client, which means the header is
+    /// finalized as of the client.
+    ///
+    /// Returns `false` if the header is out of the client, or the MMR proof doesn't match, and
+    /// returns an error only if the MMR proof is malformed.
+    pub fn contains_header(
         &self,
         header: packed::HeaderReader,
         header_mmr_proof: packed::MmrProofReader,
-    ) -> Result<bool, MMRError> {
+    ) -> Result<bool, HeaderVerificationError> {
         let header_slot = header.slot().unpack();
+        if self.header_depth(header_slot).is_none() {
+            debug!(
+                "header#{header_slot} is out of the client [{}, {}]",
+                self.minimal_slot, self.maximal_slot
+            );
+            return Ok(false);
+        }
+        self.verify_headers(iter::once(header), header_mmr_proof)
+            .map_err(|_| HeaderVerificationError::Other)
+    }
+
+    /// The count of slots after the slot in the client, which is 0 for the maximal slot.
+    ///
+    /// Returns `None` if the slot is out of the client.
+    pub fn header_depth(&self, slot: u64) -> Option<u64> {
+        if self.minimal_slot > slot || self.maximal_slot < slot {
+            None
+        } else {
+            Some(self.maximal_slot - slot)
+        }
+    }
+
+    /// Verifies a field of the execution payload, in the body of a block.
+    pub fn verify_packed_execution_payload_field_proof(
+        &self,
+        spec: &core::ChainSpec,
+        field_proof: packed::ExecutionPayloadFieldProofReader,
+    ) -> Result<(), FieldVerificationError> {
+        self.verify_header_in_client(
+            field_proof.header(),
+            field_proof.header_mmr_proof(),
+            "execution payload field proof",
+        )?;
+        field_proof.unpack().verify_in_header(spec)
+    }
+
+    /// Verifies an account and its storage slots, in the state of a block.
+    ///
+    /// The state root is proved in the block body, the account is proved in the state root, and
+    /// each storage slot is proved in the storage root of the account.
+    pub fn verify_packed_state_proof(
+        &self,
+        spec: &core::ChainSpec,
+        state_proof: packed::StateProofReader,
+    ) -> Result<(), StateVerificationError> {
+        self.verify_header_in_client(
+            state_proof.header(),
+            state_proof.header_mmr_proof(),
+            "state proof",
+        )?;
+        state_proof.unpack().verify_in_header(spec)
+    }
+
+    /// Verifies a withdrawal, in the body of a block since Capella.
+    pub fn verify_packed_withdrawal_proof(
+        &self,
+        spec: &core::ChainSpec,
+        withdrawal_proof: packed::WithdrawalProofReader,
+    ) -> Result<(), WithdrawalVerificationError> {
+        self.verify_header_in_client(
+            withdrawal_proof.header(),
+            withdrawal_proof.header_mmr_proof(),
+            "withdrawal proof",
+        )?;
+        withdrawal_proof.unpack().verify_in_header(spec)
+    }
+
+    /// Verifies a historical block, which is linked to an anchor header in the client by the
+    /// block roots in the state of the anchor header.
+    ///
+    /// The historical block could be before the client, or not in the client at all. After it's
+    /// verified, its header could be used as a verified header, e.g. in
+    /// [`core::TransactionProof::verify_packed_payload`], but not in the proofs which require the
+    /// header in the headers MMR.
+    pub fn verify_packed_historical_block_proof(
+        &self,
+        spec: &core::ChainSpec,
+        block_proof: packed::HistoricalBlockProofReader,
+    ) -> Result<(), HistoricalBlockVerificationError> {
+        self.verify_header_in_client(
+            block_proof.anchor_header(),
+            block_proof.anchor_header_mmr_proof(),
+            "historical block proof",
+        )?;
+        block_proof.unpack().verify_in_header(spec)
+    }
+
+    /// Verifies an ancestor header in the last `SLOTS_PER_HISTORICAL_ROOT` slots before an anchor
+    /// header in the client, which is proved in `block_roots` of the state of the anchor header.
+    ///
+    /// It's cheaper than relaying all headers between them into the headers MMR. Unlike
+    /// [`Self::verify_packed_historical_block_proof`], the proofs through `historical_summaries`
+    /// are rejected.
+    pub fn verify_ancestor_header(
+        &self,
+        spec: &core::ChainSpec,
+        block_proof: packed::HistoricalBlockProofReader,
+    ) -> Result<(), HistoricalBlockVerificationError> {
+        let anchor_slot: u64 = block_proof.anchor_header().slot().unpack();
+        let header_slot: u64 = block_proof.header().slot().unpack();
+        let is_in_block_roots = header_slot
+            .checked_add(spec.preset.slots_per_historical_root)
+            .map(|end_slot| end_slot >= anchor_slot)
+            .unwrap_or(false);
+        if !is_in_block_roots {
+            warn!(
+                "failed: verify ancestor header#{header_slot} since it isn't in the block roots \
+                of anchor header#{anchor_slot}"
+            );
+            return Err(HistoricalBlockVerificationError::AnchorSlot);
+        }
+        self.verify_packed_historical_block_proof(spec, block_proof)
+    }
+
+    /// Verifies a header of a proof in the client, which requires the header to be in the slots
+    /// of the client and proved by the MMR proof.
+    ///
+    /// The name of the proof is only used in logs.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    fn verify_header_in_client(
+        &self,
+        header: packed::HeaderReader,
+        header_mmr_proof: packed::MmrProofReader,
+        proof_name: &str,
+    ) -> Result<(), HeaderVerificationError> {
+        let header_slot = header.slot().unpack();
+        if self.header_depth(header_slot).is_none() {
+            warn!(
+                "failed: verify slots for header#{header_slot} of {proof_name} \
+                (client: [{}, {}])",
+                self.minimal_slot, self.maximal_slot
+            );
+            return Err(HeaderVerificationError::Unsynchronized);
+        }
+        if !self.contains_header(header, header_mmr_proof)? {
+            warn!("failed: verify MMR proof for header#{header_slot} of {proof_name}");
+            return Err(HeaderVerificationError::HeaderMmrProof);
+        }
+        debug!("passed: verify MMR proof for header#{header_slot} of {proof_name}");
+        Ok(())
+    }
+
+    fn verify_headers<'r, I>(
+        &self,
+        headers: I,
+        headers_mmr_proof: packed::MmrProofReader,
+    ) -> Result<bool, MMRError>
+    where
+        I: Iterator<Item = packed::HeaderReader<'r>>,
+    {
         let proof: mmr::MMRProof = {
             let max_index = self.maximal_slot - self.minimal_slot;
             let mmr_size = leaf_index_to_mmr_size(max_index);
             trace!(
-                "verify MMR proof for header#{header_slot} with \
+                "verify MMR proof for headers with \
                 MMR {{ size: {mmr_size}, max-index: {max_index} }}"
             );
-            let proof = header_mmr_proof
+            let proof = headers_mmr_proof
                 .iter()
                 .map(|r| r.to_entity())
                 .collect::<Vec<_>>();
             mmr::MMRProof::new(mmr_size, proof)
         };
-        let digests_with_positions = {
-            let index = header_slot - self.minimal_slot;
-            let position = leaf_index_to_pos(index);
-            let header_with_cache = header.unpack().calc_cache();
-            trace!(
-                "verify MMR proof for header#{header_slot} with \
-                index: {index}, position: {position}, root: {:#x}",
-                header_with_cache.root
-            );
-            let digest = header_with_cache.digest();
-            vec![(position, digest)]
-        };
+        let digests_with_positions = headers
+            .map(|header| {
+                let header_slot = header.slot().unpack();
+                let index = header_slot - self.minimal_slot;
+                let position = leaf_index_to_pos(index);
+                let header_with_cache = header.unpack().calc_cache();
+                trace!(
+                    "verify MMR proof for header#{header_slot} with \
+                    index: {index}, position: {position}, root: {:#x}",
+                    header_with_cache.root
+                );
+                let digest = header_with_cache.digest();
+                (position, digest)
+            })
+            .collect::<Vec<_>>();
         proof.verify(self.headers_mmr_root.pack(), digests_with_positions)
     }
 }
 
+impl core::ClientInfo {
+    /// The id of the client cell which will be replaced by the next update.
+    ///
+    /// The client cells are a ring buffer of `cells_count` cells, the client cell after the latest
+    /// one is the oldest one, which is replaced by the next client.
+    pub fn next_id(&self, cells_count: u8) -> Result<u8, ClientCellsError> {
+        if cells_count == 0 {
+            return Err(ClientCellsError::CellsCount);
+        }
+        if self.last_id >= cells_count {
+            return Err(ClientCellsError::LastId);
+        }
+        Ok((self.last_id + 1) % cells_count)
+    }
+
+    /// Puts the new client into the ring buffer of client cells.
+    ///
+    /// Returns the new info and the new client with the id of the replaced client cell.
+    pub fn rotate(
+        &self,
+        cells_count: u8,
+        mut new_client: core::Client,
+    ) -> Result<(Self, core::Client), ClientCellsError> {
+        let next_id = self.next_id(cells_count)?;
+        new_client.id = next_id;
+        let new_info = Self {
+            last_id: next_id,
+            minimal_updates_count: self.minimal_updates_count,
+        };
+        Ok((new_info, new_client))
+    }
+
+    /// Verifies an update of the ring buffer of client cells: the replaced client cell should be
+    /// the oldest one, and the new client and the new info should use its id.
+    pub fn verify_rotation(
+        &self,
+        cells_count: u8,
+        replaced_client: &core::Client,
+        new_info: &Self,
+        new_client: &core::Client,
+    ) -> Result<(), ClientCellsError> {
+        let next_id = self.next_id(cells_count)?;
+        if replaced_client.id != next_id {
+            error!(
+                "the replaced client#{} is not the oldest client#{next_id}",
+                replaced_client.id
+            );
+            return Err(ClientCellsError::ReplacedClientId);
+        }
+        if new_client.id != next_id {
+            error!(
+                "the id of the new client#{} should be {next_id}",
+                new_client.id
+            );
+            return Err(ClientCellsError::NewClientId);
+        }
+        if new_info.last_id != next_id {
+            error!(
+                "the last id in the new info {} should be {next_id}",
+                new_info.last_id
+            );
+            return Err(ClientCellsError::NewLastId);
+        }
+        if new_info.minimal_updates_count != self.minimal_updates_count {
+            return Err(ClientCellsError::MinimalUpdatesCount);
+        }
+        Ok(())
+    }
+
+    /// Verifies a change of the minimal updates count, which is a governance action.
+    ///
+    /// No client cells are replaced in the change, and only the owner in the type args could
+    /// change it, which should be checked by the caller.
+    /// The minimal updates count could be raised or lowered, but it should be positive, and it
+    /// should not be more than the slots in an epoch, so it could be met by a proof update with
+    /// the finalized headers of an epoch or two, otherwise the client cells are frozen.
+    pub fn verify_minimal_updates_count_change(
+        &self,
+        spec: &core::ChainSpec,
+        new_info: &Self,
+    ) -> Result<(), ClientCellsError> {
+        if new_info.last_id != self.last_id {
+            return Err(ClientCellsError::NewLastId);
+        }
+        if new_info.minimal_updates_count == self.minimal_updates_count {
+            error!(
+                "the minimal updates count {} is not changed",
+                self.minimal_updates_count
+            );
+            return Err(ClientCellsError::MinimalUpdatesCount);
+        }
+        let slots_per_epoch = spec.preset.slots_per_epoch;
+        if new_info.minimal_updates_count == 0
+            || u64::from(new_info.minimal_updates_count) > slots_per_epoch
+        {
+            error!(
+                "the minimal updates count {} is not in [1, {slots_per_epoch}]",
+                new_info.minimal_updates_count
+            );
+            return Err(ClientCellsError::MinimalUpdatesCount);
+        }
+        Ok(())
+    }
+
+    /// Selects the latest client, which a reader should trust, from the client cells.
+    ///
+    /// The other client cells are still valid, but they are older than the latest one.
+    pub fn select_latest_client<'a, I>(&self, clients: I) -> Option<&'a core::Client>
+    where
+        I: IntoIterator<Item = &'a core::Client>,
+    {
+        clients.into_iter().find(|client| client.id == self.last_id)
+    }
+}
+
+impl<'r> packed::SyncCommitteeReader<'r> {
+    /// Calculates the root of the sync committee, as the SSZ container [`SyncCommittee`].
+    ///
+    /// [`SyncCommittee`]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/beacon-chain.md#synccommittee
+    pub fn calc_root(&self, spec: &core::ChainSpec) -> Hash256 {
+        let pubkeys_roots = self
+            .pubkeys()
+            .iter()
+            .flat_map(|pubkey| tree_hash::merkle_root(pubkey.raw_data(), 0).to_fixed_bytes())
+            .collect::<Vec<_>>();
+        let pubkeys_root =
+            tree_hash::merkle_root(&pubkeys_roots, spec.preset.sync_committee_size as usize);
+        let aggregate_pubkey_root = tree_hash::merkle_root(self.aggregate_pubkey().raw_data(), 0);
+        let root = hash32_concat(pubkeys_root.as_bytes(), aggregate_pubkey_root.as_bytes());
+        Hash256::from(root)
+    }
+}
+
 impl core::TransactionProof {
     pub fn verify_packed_payload(
         &self,
+        spec: &core::ChainSpec,
         payload: packed::TransactionPayloadReader,
     ) -> Result<(), TxVerificationError> {
-        self.verify_transaction(payload.transaction().raw_data())?;
-        self.verify_receipt(payload.receipt().raw_data())
-    }
-
-    pub fn verify_transaction(&self, transaction: &[u8]) -> Result<(), TxVerificationError> {
-        // Since `MAX_BYTES_PER_TRANSACTION`.
-        VariableList::<u8, typenum::U1073741824>::new(transaction.to_vec())
-            .map_err(|_| TxVerificationError::Other)
-            .and_then(|tx| {
-                let tx_root = tx.tree_hash_root();
-                let tx_index = self.transaction_index as usize;
-                let tx_in_block_index = if self.header.slot
-                    < specs::helpers::compute_start_slot_at_epoch(specs::capella::FORK_EPOCH)
-                {
-                    tx_index + specs::bellatrix::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET
-                } else {
-                    tx_index + specs::capella::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET
-                };
-                if !ssz::verify_merkle_proof(
-                    self.header.body_root,
-                    tx_root,
-                    &self.transaction_ssz_proof,
-                    tx_in_block_index,
-                ) {
-                    warn!(
-                        "failed: verify SSZ proof for transaction {tx_root:#x} \
-                        (index: {tx_index}, offset: {tx_in_block_index})"
-                    );
-                    Err(TxVerificationError::TransactionSszProof)
-                } else {
-                    debug!(
-                        "passed: verify SSZ proof for transaction {tx_root:#x} \
-                        (index: {tx_index}, offset: {tx_in_block_index})"
-                    );
-                    Ok(())
-                }
-            })
+        self.verify_transaction(spec, payload.transaction().raw_data())?;
+        self.verify_receipt(spec, payload.receipt().raw_data())
+    }
+
+    pub fn verify_transaction(
+        &self,
+        spec: &core::ChainSpec,
+        transaction: &[u8],
+    ) -> Result<(), TxVerificationError> {
+        verify_transaction_in_block_body(
+            spec,
+            &self.header,
+            self.transaction_index,
+            &self.transaction_ssz_proof,
+            transaction,
+        )
     }
 
-    pub fn verify_receipt(&self, receipt: &[u8]) -> Result<(), TxVerificationError> {
-        let key = encode(&self.transaction_index);
-        let receipts_root_in_block_body = if self.header.slot
-            < specs::helpers::compute_start_slot_at_epoch(specs::capella::FORK_EPOCH)
+    pub fn verify_receipt(
+        &self,
+        spec: &core::ChainSpec,
+        receipt: &[u8],
+    ) -> Result<(), TxVerificationError> {
+        verify_receipt_in_receipts_root(
+            self.receipts_root,
+            self.transaction_index,
+            &self.receipt_mpt_proof,
+            receipt,
+        )?;
+        verify_receipts_root_in_block_body(
+            spec,
+            &self.header,
+            self.receipts_root,
+            &self.receipts_root_ssz_proof,
+        )
+    }
+}
+
+impl core::BlockTransactionsProof {
+    /// Verifies the payloads, which should be in the same order as the transactions proofs.
+    ///
+    /// The receipts root is verified only once for all payloads.
+    pub fn verify_packed_payloads(
+        &self,
+        spec: &core::ChainSpec,
+        payloads: packed::TransactionPayloadVecReader,
+    ) -> Result<(), TxVerificationError> {
+        if payloads.len() != self.transactions.len() {
+            warn!(
+                "failed: payloads count {} isn't same as transactions count {}",
+                payloads.len(),
+                self.transactions.len()
+            );
+            return Err(TxVerificationError::Other);
+        }
+        verify_receipts_root_in_block_body(
+            spec,
+            &self.header,
+            self.receipts_root,
+            &self.receipts_root_ssz_proof,
+        )?;
+        for (tx_proof, payload) in self.transactions.iter().zip(payloads.iter()) {
+            verify_transaction_in_block_body(
+                spec,
+                &self.header,
+                tx_proof.transaction_index,
+                &tx_proof.transaction_ssz_proof,
+                payload.transaction().raw_data(),
+            )?;
+            verify_receipt_in_receipts_root(
+                self.receipts_root,
+                tx_proof.transaction_index,
+                &tx_proof.receipt_mpt_proof,
+                payload.receipt().raw_data(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl core::ExecutionPayloadFieldProof {
+    /// Verifies the field root in the block body.
+    ///
+    /// The header should be verified by
+    /// [`core::Client::verify_packed_execution_payload_field_proof`].
+    pub fn verify_in_header(&self, spec: &core::ChainSpec) -> Result<(), FieldVerificationError> {
+        let field_index = usize::from(self.field_index);
+        let (fields_count, field_in_block_body) = match spec.compute_fork_at_slot(self.header.slot)
         {
-            specs::bellatrix::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY
+            Fork::Fulu => (
+                specs::fulu::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+                specs::fulu::generalized_index::execution_payload_field_in_block_body(field_index),
+            ),
+            Fork::Electra => (
+                specs::electra::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+                specs::electra::generalized_index::execution_payload_field_in_block_body(
+                    field_index,
+                ),
+            ),
+            Fork::Deneb => (
+                specs::deneb::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+                specs::deneb::generalized_index::execution_payload_field_in_block_body(field_index),
+            ),
+            Fork::Capella => (
+                specs::capella::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+                specs::capella::generalized_index::execution_payload_field_in_block_body(
+                    field_index,
+                ),
+            ),
+            _ => (
+                specs::bellatrix::containers::EXECUTION_PAYLOAD_FIELDS_COUNT,
+                specs::bellatrix::generalized_index::execution_payload_field_in_block_body(
+                    field_index,
+                ),
+            ),
+        };
+        if field_index >= fields_count {
+            warn!(
+                "failed: verify execution payload field#{field_index} \
+                since there are only {fields_count} fields"
+            );
+            return Err(FieldVerificationError::FieldIndex);
+        }
+        if !ssz::verify_merkle_proof(
+            self.header.body_root,
+            self.field_root,
+            &self.field_ssz_proof,
+            field_in_block_body,
+        ) {
+            warn!(
+                "failed: verify SSZ proof for execution payload field#{field_index} {:#x}",
+                self.field_root
+            );
+            Err(FieldVerificationError::FieldSszProof)
         } else {
-            specs::capella::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY
+            debug!(
+                "passed: verify SSZ proof for execution payload field#{field_index} {:#x}",
+                self.field_root
+            );
+            Ok(())
+        }
+    }
+
+    // The indexes of the following fields are not changed in all forks.
+
+    /// The block hash of the execution payload, if it's the proved field.
+    pub fn block_hash(&self) -> Option<Hash256> {
+        let index = specs::capella::containers::BLOCK_HASH_IN_EXECUTION_PAYLOAD_INDEX;
+        (usize::from(self.field_index) == index).then_some(self.field_root)
+    }
+
+    /// The state root of the execution payload, if it's the proved field.
+    pub fn state_root(&self) -> Option<Hash256> {
+        let index = specs::capella::containers::STATE_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
+        (usize::from(self.field_index) == index).then_some(self.field_root)
+    }
+
+    /// The block number of the execution payload, if it's the proved field.
+    pub fn block_number(&self) -> Option<u64> {
+        let index = specs::capella::containers::BLOCK_NUMBER_IN_EXECUTION_PAYLOAD_INDEX;
+        if usize::from(self.field_index) == index {
+            uint64_from_root(&self.field_root)
+        } else {
+            None
+        }
+    }
+
+    /// The timestamp of the execution payload, if it's the proved field.
+    pub fn timestamp(&self) -> Option<u64> {
+        let index = specs::capella::containers::TIMESTAMP_IN_EXECUTION_PAYLOAD_INDEX;
+        if usize::from(self.field_index) == index {
+            uint64_from_root(&self.field_root)
+        } else {
+            None
+        }
+    }
+}
+
+impl core::StateProof {
+    /// Verifies the state root in the block body, the account in the state root, and the storage
+    /// slots in the storage root of the account.
+    ///
+    /// The header should be verified by [`core::Client::verify_packed_state_proof`].
+    pub fn verify_in_header(&self, spec: &core::ChainSpec) -> Result<(), StateVerificationError> {
+        self.verify_state_root(spec)?;
+        self.verify_account()?;
+        for storage_proof in &self.storage_proofs {
+            self.verify_storage(storage_proof)?;
+        }
+        Ok(())
+    }
+
+    fn verify_state_root(&self, spec: &core::ChainSpec) -> Result<(), StateVerificationError> {
+        let state_root_in_block_body = match spec.compute_fork_at_slot(self.header.slot) {
+            Fork::Fulu => specs::fulu::generalized_index::STATE_ROOT_IN_BLOCK_BODY,
+            Fork::Electra => specs::electra::generalized_index::STATE_ROOT_IN_BLOCK_BODY,
+            Fork::Deneb => specs::deneb::generalized_index::STATE_ROOT_IN_BLOCK_BODY,
+            Fork::Capella => specs::capella::generalized_index::STATE_ROOT_IN_BLOCK_BODY,
+            _ => specs::bellatrix::generalized_index::STATE_ROOT_IN_BLOCK_BODY,
         };
+        if !ssz::verify_merkle_proof(
+            self.header.body_root,
+            self.state_root,
+            &self.state_root_ssz_proof,
+            state_root_in_block_body,
+        ) {
+            warn!(
+                "failed: verify SSZ proof for state root {:#x}",
+                self.state_root
+            );
+            Err(StateVerificationError::StateRootSszProof)
+        } else {
+            debug!(
+                "passed: verify SSZ proof for state root {:#x}",
+                self.state_root
+            );
+            Ok(())
+        }
+    }
+
+    fn verify_account(&self) -> Result<(), StateVerificationError> {
+        let key = trie::keccak256(&self.address);
+        let account = trie::encode_account(
+            self.nonce,
+            &self.balance,
+            self.storage_root.as_fixed_bytes(),
+            self.code_hash.as_fixed_bytes(),
+        );
         if !trie::verify_proof(
-            &self.receipt_mpt_proof,
-            self.receipts_root.as_bytes(),
+            &self.account_mpt_proof,
+            self.state_root.as_bytes(),
             &key,
-            receipt,
+            &account,
         ) {
             warn!(
-                "failed: verify MPT proof for {}-th receipt with root {:#x}",
-                self.transaction_index, self.receipts_root
+                "failed: verify MPT proof for account (key: {:#x}) with state root {:#x}",
+                Hash256::from(key),
+                self.state_root
+            );
+            Err(StateVerificationError::AccountMptProof)
+        } else {
+            debug!(
+                "passed: verify MPT proof for account (key: {:#x}) with state root {:#x}",
+                Hash256::from(key),
+                self.state_root
             );
-            Err(TxVerificationError::ReceiptMptProof)
-        } else if !ssz::verify_merkle_proof(
+            Ok(())
+        }
+    }
+
+    fn verify_storage(
+        &self,
+        storage_proof: &core::StorageProof,
+    ) -> Result<(), StateVerificationError> {
+        let key = trie::keccak256(storage_proof.key.as_bytes());
+        let value = trie::encode_storage_value(&storage_proof.value);
+        if !trie::verify_proof(
+            &storage_proof.proof,
+            self.storage_root.as_bytes(),
+            &key,
+            &value,
+        ) {
+            warn!(
+                "failed: verify MPT proof for storage {:#x} with storage root {:#x}",
+                storage_proof.key, self.storage_root
+            );
+            Err(StateVerificationError::StorageMptProof)
+        } else {
+            debug!(
+                "passed: verify MPT proof for storage {:#x} with storage root {:#x}",
+                storage_proof.key, self.storage_root
+            );
+            Ok(())
+        }
+    }
+}
+
+impl core::Withdrawal {
+    /// Calculates the root of the withdrawal, as the SSZ container [`Withdrawal`].
+    ///
+    /// [`Withdrawal`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#withdrawal
+    pub fn calc_root(&self) -> Hash256 {
+        let mut address_chunk = [0u8; 32];
+        address_chunk[..20].copy_from_slice(&self.address);
+        let leaves = [
+            self.index.tree_hash_root().to_fixed_bytes(),
+            self.validator_index.tree_hash_root().to_fixed_bytes(),
+            address_chunk,
+            self.amount.tree_hash_root().to_fixed_bytes(),
+        ]
+        .concat();
+        tree_hash::merkle_root(&leaves, specs::capella::containers::WITHDRAWAL_FIELDS_COUNT)
+    }
+}
+
+impl core::WithdrawalProof {
+    /// Verifies the withdrawal in the block body.
+    ///
+    /// The header should be verified by [`core::Client::verify_packed_withdrawal_proof`].
+    pub fn verify_in_header(
+        &self,
+        spec: &core::ChainSpec,
+    ) -> Result<(), WithdrawalVerificationError> {
+        let max_withdrawals_per_payload = spec.preset.max_withdrawals_per_payload;
+        let withdrawals_depth = spec.preset.withdrawals_depth();
+
+        let header_slot = self.header.slot;
+        let withdrawal_in_block_body_offset = match spec.compute_fork_at_slot(header_slot) {
+            Fork::Fulu => {
+                specs::fulu::generalized_index::withdrawal_in_block_body_offset(withdrawals_depth)
+            }
+            Fork::Electra => specs::electra::generalized_index::withdrawal_in_block_body_offset(
+                withdrawals_depth,
+            ),
+            Fork::Deneb => {
+                specs::deneb::generalized_index::withdrawal_in_block_body_offset(withdrawals_depth)
+            }
+            Fork::Capella => specs::capella::generalized_index::withdrawal_in_block_body_offset(
+                withdrawals_depth,
+            ),
+            _ => {
+                warn!("failed: verify withdrawal since header#{header_slot} is before Capella");
+                return Err(WithdrawalVerificationError::BeforeCapella);
+            }
+        };
+        let withdrawal_index = self.withdrawal_index;
+        if withdrawal_index >= max_withdrawals_per_payload {
+            warn!(
+                "failed: verify withdrawal#{withdrawal_index} since there are at most \
+                {max_withdrawals_per_payload} withdrawals"
+            );
+            return Err(WithdrawalVerificationError::WithdrawalIndex);
+        }
+        let withdrawal_root = self.withdrawal.calc_root();
+        let withdrawal_in_block_body = withdrawal_in_block_body_offset + withdrawal_index as usize;
+        if !ssz::verify_merkle_proof(
             self.header.body_root,
-            self.receipts_root,
-            &self.receipts_root_ssz_proof,
-            receipts_root_in_block_body,
+            withdrawal_root,
+            &self.withdrawal_ssz_proof,
+            withdrawal_in_block_body,
         ) {
             warn!(
-                "failed: verify SSZ proof for {}-th receipt with root {:#x}",
-                self.transaction_index, self.receipts_root
+                "failed: verify SSZ proof for withdrawal#{withdrawal_index} {withdrawal_root:#x} \
+                in header#{header_slot}"
             );
-            Err(TxVerificationError::ReceiptsRootSszProof)
+            Err(WithdrawalVerificationError::WithdrawalSszProof)
         } else {
             debug!(
-                "passed: verify MPT & SSZ proofs for {}-th receipt with root {:#x}",
-                self.transaction_index, self.receipts_root
+                "passed: verify SSZ proof for withdrawal#{withdrawal_index} {withdrawal_root:#x} \
+                in header#{header_slot}"
             );
             Ok(())
         }
     }
 }
+
+impl core::HistoricalBlockProof {
+    /// Verifies the root of the historical header in the state of the anchor header.
+    ///
+    /// If the historical header is in the last `SLOTS_PER_HISTORICAL_ROOT` slots before the
+    /// anchor header, it's proved in `block_roots` of the state; otherwise, it's proved in the
+    /// `block_summary_root` of `historical_summaries` of the state, which starts from Capella.
+    ///
+    /// The anchor header should be verified by
+    /// [`core::Client::verify_packed_historical_block_proof`].
+    pub fn verify_in_header(
+        &self,
+        spec: &core::ChainSpec,
+    ) -> Result<(), HistoricalBlockVerificationError> {
+        let header_slot = self.header.slot;
+        let anchor_slot = self.anchor_header.slot;
+        if header_slot >= anchor_slot {
+            warn!(
+                "failed: verify historical header#{header_slot} since it isn't before \
+                anchor header#{anchor_slot}"
+            );
+            return Err(HistoricalBlockVerificationError::AnchorSlot);
+        }
+
+        let slots_per_historical_root = spec.preset.slots_per_historical_root;
+        let block_roots_depth = spec.preset.block_roots_depth();
+        let (block_roots_index, historical_summaries_index) =
+            match spec.compute_fork_at_slot(anchor_slot) {
+                Fork::Fulu => (
+                    specs::fulu::BLOCK_ROOTS_INDEX,
+                    specs::fulu::HISTORICAL_SUMMARIES_INDEX,
+                ),
+                Fork::Electra => (
+                    specs::electra::BLOCK_ROOTS_INDEX,
+                    specs::electra::HISTORICAL_SUMMARIES_INDEX,
+                ),
+                _ => (
+                    specs::capella::BLOCK_ROOTS_INDEX,
+                    specs::capella::HISTORICAL_SUMMARIES_INDEX,
+                ),
+            };
+        let header_in_block_roots = (header_slot % slots_per_historical_root) as usize;
+        let header_in_state = if anchor_slot - header_slot <= slots_per_historical_root {
+            (block_roots_index << block_roots_depth) + header_in_block_roots
+        } else {
+            // A historical summary is appended at the end of each `SLOTS_PER_HISTORICAL_ROOT`
+            // slots since Capella, so the first one is for the slots which contain the Capella
+            // fork, even if the fork isn't aligned to them.
+            // The start slot of the Capella fork overflows if it's not scheduled.
+            let capella_summary_period_opt = spec
+                .fork_config(Fork::Capella)
+                .epoch
+                .checked_mul(spec.preset.slots_per_epoch)
+                .map(|capella_start_slot| capella_start_slot / slots_per_historical_root);
+            let header_summary_period = header_slot / slots_per_historical_root;
+            let capella_summary_period = match capella_summary_period_opt {
+                Some(period) if header_summary_period >= period => period,
+                _ => {
+                    warn!(
+                        "failed: verify historical header#{header_slot} since it's before \
+                        Capella and not in the block roots of anchor header#{anchor_slot}"
+                    );
+                    return Err(HistoricalBlockVerificationError::BeforeCapella);
+                }
+            };
+            let summary_index = (header_summary_period - capella_summary_period) as usize;
+            // The data of the list is the left child, and the length is the right child.
+            let summaries_depth = ssz::ceil_depth(specs::phase0::HISTORICAL_ROOTS_LIMIT);
+            let summary_in_state =
+                ((historical_summaries_index * 2) << summaries_depth) + summary_index;
+            // The `block_summary_root` is the first one of the 2 fields of a historical summary.
+            let block_summary_root_in_state = summary_in_state * 2;
+            (block_summary_root_in_state << block_roots_depth) + header_in_block_roots
+        };
+
+        let header_root = self.header.tree_hash_root();
+        if !ssz::verify_merkle_proof(
+            self.anchor_header.state_root,
+            header_root,
+            &self.header_ssz_proof,
+            header_in_state,
+        ) {
+            warn!(
+                "failed: verify SSZ proof for historical header#{header_slot} {header_root:#x} \
+                in the state of anchor header#{anchor_slot}"
+            );
+            Err(HistoricalBlockVerificationError::HeaderSszProof)
+        } else {
+            debug!(
+                "passed: verify SSZ proof for historical header#{header_slot} {header_root:#x} \
+                in the state of anchor header#{anchor_slot}"
+            );
+            Ok(())
+        }
+    }
+}
+
+fn verify_transaction_in_block_body(
+    spec: &core::ChainSpec,
+    header: &core::Header,
+    tx_index: u64,
+    transaction_ssz_proof: &[Hash256],
+    transaction: &[u8],
+) -> Result<(), TxVerificationError> {
+    // Since `MAX_BYTES_PER_TRANSACTION`.
+    VariableList::<u8, typenum::U1073741824>::new(transaction.to_vec())
+        .map_err(|_| TxVerificationError::Other)
+        .and_then(|tx| {
+            let tx_root = tx.tree_hash_root();
+            let tx_index = tx_index as usize;
+            let tx_in_block_index = tx_index
+                + match spec.compute_fork_at_slot(header.slot) {
+                    Fork::Fulu => specs::fulu::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET,
+                    Fork::Electra => {
+                        specs::electra::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET
+                    }
+                    Fork::Deneb => {
+                        specs::deneb::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET
+                    }
+                    Fork::Capella => {
+                        specs::capella::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET
+                    }
+                    _ => specs::bellatrix::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET,
+                };
+            if !ssz::verify_merkle_proof(
+                header.body_root,
+                tx_root,
+                transaction_ssz_proof,
+                tx_in_block_index,
+            ) {
+                warn!(
+                    "failed: verify SSZ proof for transaction {tx_root:#x} \
+                    (index: {tx_index}, offset: {tx_in_block_index})"
+                );
+                Err(TxVerificationError::TransactionSszProof)
+            } else {
+                debug!(
+                    "passed: verify SSZ proof for transaction {tx_root:#x} \
+                    (index: {tx_index}, offset: {tx_in_block_index})"
+                );
+                Ok(())
+            }
+        })
+}
+
+fn verify_receipt_in_receipts_root(
+    receipts_root: Hash256,
+    tx_index: u64,
+    receipt_mpt_proof: &[Vec<u8>],
+    receipt: &[u8],
+) -> Result<(), TxVerificationError> {
+    let key = encode(&tx_index);
+    if !trie::verify_proof(receipt_mpt_proof, receipts_root.as_bytes(), &key, receipt) {
+        warn!("failed: verify MPT proof for {tx_index}-th receipt with root {receipts_root:#x}");
+        Err(TxVerificationError::ReceiptMptProof)
+    } else {
+        debug!("passed: verify MPT proof for {tx_index}-th receipt with root {receipts_root:#x}");
+        Ok(())
+    }
+}
+
+fn verify_receipts_root_in_block_body(
+    spec: &core::ChainSpec,
+    header: &core::Header,
+    receipts_root: Hash256,
+    receipts_root_ssz_proof: &[Hash256],
+) -> Result<(), TxVerificationError> {
+    let receipts_root_in_block_body = match spec.compute_fork_at_slot(header.slot) {
+        Fork::Fulu => specs::fulu::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY,
+        Fork::Electra => specs::electra::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY,
+        Fork::Deneb => specs::deneb::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY,
+        Fork::Capella => specs::capella::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY,
+        _ => specs::bellatrix::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY,
+    };
+    if !ssz::verify_merkle_proof(
+        header.body_root,
+        receipts_root,
+        receipts_root_ssz_proof,
+        receipts_root_in_block_body,
+    ) {
+        warn!("failed: verify SSZ proof for receipts root {receipts_root:#x}");
+        Err(TxVerificationError::ReceiptsRootSszProof)
+    } else {
+        debug!("passed: verify SSZ proof for receipts root {receipts_root:#x}");
+        Ok(())
+    }
+}
+
+// The hash tree root of an `uint64` is its little-endian bytes, padded with zeros.
+fn uint64_from_root(root: &Hash256) -> Option<u64> {
+    let bytes = root.as_bytes();
+    if bytes[8..].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    Some(u64::from_le_bytes(buf))
+}
@@ -0,0 +1,43 @@
+use alloc::vec::Vec;
+
+use blst::{
+    min_pk::{AggregatePublicKey, PublicKey, Signature},
+    BLST_ERROR,
+};
+
+// The ciphersuite of BLS signatures, which is used in Ethereum proof-of-stake.
+// Ref: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#bls-signatures
+pub const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+pub const PUBLIC_KEY_BYTES_LEN: usize = 48;
+pub const SIGNATURE_BYTES_LEN: usize = 96;
+
+// Verify an aggregate signature, which all public keys signed the same message.
+//
+// The public keys are not validated one by one, they should be trusted, for example, they are
+// committed by the beacon state; but the aggregate public key is still validated.
+//
+// Ref: `FastAggregateVerify` in https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-04#section-3.3.4
+pub fn fast_aggregate_verify(pubkeys: &[&[u8]], message: &[u8], signature: &[u8]) -> bool {
+    if pubkeys.is_empty() {
+        return false;
+    }
+    let pubkeys = match pubkeys
+        .iter()
+        .map(|pubkey| PublicKey::from_bytes(pubkey))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(pubkeys) => pubkeys,
+        Err(_) => return false,
+    };
+    let pubkeys_refs = pubkeys.iter().collect::<Vec<_>>();
+    let pubkey = match AggregatePublicKey::aggregate(&pubkeys_refs, false) {
+        Ok(aggregate_pubkey) => aggregate_pubkey.to_public_key(),
+        Err(_) => return false,
+    };
+    let signature = match Signature::from_bytes(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    signature.verify(true, message, DST, &[], &pubkey, true) == BLST_ERROR::BLST_SUCCESS
+}
@@ -1,3 +1,5 @@
+pub mod bls;
 pub mod mmr;
+pub mod receipt;
 pub mod ssz;
 pub mod trie;
@@ -0,0 +1,260 @@
+//! Decode the receipts of transactions, which are encoded as the values in the receipts trie.
+//!
+//! Ref: [EIP-2718: Receipts](https://eips.ethereum.org/EIPS/eip-2718#receipts)
+
+use rlp::{DecoderError, Rlp};
+
+use crate::error::ReceiptError;
+
+pub const LOGS_BLOOM_SIZE: usize = 256;
+
+pub type Address = [u8; 20];
+pub type Topic = [u8; 32];
+
+/// A decoded receipt, which borrows all data from the encoded receipt.
+///
+/// Only the receipts after the Byzantium fork are supported, which contain the status of the
+/// transaction instead of the intermediate state root.
+pub struct Receipt<'a> {
+    transaction_type: u8,
+    status: bool,
+    cumulative_gas_used: u64,
+    logs_bloom: &'a [u8],
+    logs: Rlp<'a>,
+}
+
+/// A log in the receipt.
+pub struct Log<'a> {
+    pub address: &'a Address,
+    pub topics: Topics<'a>,
+    pub data: &'a [u8],
+}
+
+/// The iterator of logs in the receipt.
+#[derive(Clone)]
+pub struct Logs<'a> {
+    rlp: Rlp<'a>,
+    index: usize,
+    count: usize,
+}
+
+/// The iterator of topics in the log.
+#[derive(Clone)]
+pub struct Topics<'a> {
+    rlp: Rlp<'a>,
+    index: usize,
+    count: usize,
+}
+
+impl<'a> Receipt<'a> {
+    /// Decodes the receipt and checks all logs in it, so the logs could be iterated without errors.
+    pub fn decode(data: &'a [u8]) -> Result<Self, ReceiptError> {
+        let (transaction_type, payload) = match data.first() {
+            Some(&first) if first >= 0xc0 => (0, data),
+            Some(&first) if first > 0 && first <= 0x7f => (first, &data[1..]),
+            _ => {
+                warn!("failed: decode receipt since unknown transaction type");
+                return Err(ReceiptError::TransactionType);
+            }
+        };
+        let rlp = Rlp::new(payload);
+        let info = rlp.payload_info().map_err(log_decoder_error)?;
+        if !rlp.is_list() || info.total() != payload.len() || rlp.item_count() != Ok(4) {
+            warn!("failed: decode receipt since it isn't a list of 4 items");
+            return Err(ReceiptError::Decode);
+        }
+
+        let status = match rlp.at(0).and_then(|item| item.data()) {
+            Ok([]) => false,
+            Ok([1]) => true,
+            _ => {
+                warn!("failed: decode status of receipt");
+                return Err(ReceiptError::Status);
+            }
+        };
+        let cumulative_gas_used = rlp.val_at(1).map_err(log_decoder_error)?;
+        let logs_bloom = rlp
+            .at(2)
+            .and_then(|item| item.data())
+            .map_err(log_decoder_error)?;
+        if logs_bloom.len() != LOGS_BLOOM_SIZE {
+            warn!(
+                "failed: decode receipt since the size of logs bloom is {}",
+                logs_bloom.len()
+            );
+            return Err(ReceiptError::Decode);
+        }
+        let logs = rlp.at(3).map_err(log_decoder_error)?;
+        if !logs.is_list() {
+            warn!("failed: decode receipt since logs isn't a list");
+            return Err(ReceiptError::Decode);
+        }
+        for item in logs.iter() {
+            Log::decode(&item)?;
+        }
+
+        let receipt = Self {
+            transaction_type,
+            status,
+            cumulative_gas_used,
+            logs_bloom,
+            logs,
+        };
+        Ok(receipt)
+    }
+
+    /// The EIP-2718 type of the transaction, `0` for the legacy transactions.
+    pub fn transaction_type(&self) -> u8 {
+        self.transaction_type
+    }
+
+    /// Whether the transaction succeeded.
+    pub fn status(&self) -> bool {
+        self.status
+    }
+
+    pub fn cumulative_gas_used(&self) -> u64 {
+        self.cumulative_gas_used
+    }
+
+    pub fn logs_bloom(&self) -> &'a [u8] {
+        self.logs_bloom
+    }
+
+    pub fn logs(&self) -> Logs<'a> {
+        Logs {
+            rlp: self.logs.clone(),
+            index: 0,
+            count: self.logs.item_count().unwrap_or(0),
+        }
+    }
+
+    pub fn log(&self, index: usize) -> Option<Log<'a>> {
+        self.logs().nth(index)
+    }
+
+    /// Verifies that the log at the index was emitted by the contract, with the first topic.
+    pub fn verify_log(
+        &self,
+        index: usize,
+        address: &Address,
+        topic0: &Topic,
+    ) -> Result<Log<'a>, ReceiptError> {
+        let log = if let Some(log) = self.log(index) {
+            log
+        } else {
+            warn!(
+                "failed: verify log#{index} since receipt only has {} logs",
+                self.logs().len()
+            );
+            return Err(ReceiptError::LogIndex);
+        };
+        if log.address != address {
+            warn!("failed: verify address of log#{index}");
+            return Err(ReceiptError::UnmatchedLogAddress);
+        }
+        if log.topics.clone().next() != Some(topic0) {
+            warn!("failed: verify topic0 of log#{index}");
+            return Err(ReceiptError::UnmatchedLogTopic);
+        }
+        Ok(log)
+    }
+}
+
+impl<'a> Log<'a> {
+    fn decode(rlp: &Rlp<'a>) -> Result<Self, ReceiptError> {
+        if !rlp.is_list() || rlp.item_count() != Ok(3) {
+            warn!("failed: decode log since it isn't a list of 3 items");
+            return Err(ReceiptError::Decode);
+        }
+        let address = rlp
+            .at(0)
+            .and_then(|item| item.data())
+            .map_err(log_decoder_error)?
+            .try_into()
+            .map_err(|_| {
+                warn!("failed: decode address of log");
+                ReceiptError::Decode
+            })?;
+        let topics = rlp.at(1).map_err(log_decoder_error)?;
+        if !topics.is_list() {
+            warn!("failed: decode log since topics isn't a list");
+            return Err(ReceiptError::Decode);
+        }
+        for item in topics.iter() {
+            let size = item.data().map_err(log_decoder_error)?.len();
+            if size != 32 {
+                warn!("failed: decode log since the size of a topic is {size}");
+                return Err(ReceiptError::Decode);
+            }
+        }
+        let data = rlp
+            .at(2)
+            .and_then(|item| item.data())
+            .map_err(log_decoder_error)?;
+        let log = Self {
+            address,
+            topics: Topics {
+                count: topics.item_count().unwrap_or(0),
+                rlp: topics,
+                index: 0,
+            },
+            data,
+        };
+        Ok(log)
+    }
+}
+
+impl<'a> Iterator for Logs<'a> {
+    type Item = Log<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let log = self
+            .rlp
+            .at(self.index)
+            .ok()
+            .and_then(|item| Log::decode(&item).ok());
+        self.index += 1;
+        log
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remain = self.count - self.index;
+        (remain, Some(remain))
+    }
+}
+
+impl<'a> ExactSizeIterator for Logs<'a> {}
+
+impl<'a> Iterator for Topics<'a> {
+    type Item = &'a Topic;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let topic = self
+            .rlp
+            .at(self.index)
+            .and_then(|item| item.data())
+            .ok()
+            .and_then(|data| data.try_into().ok());
+        self.index += 1;
+        topic
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remain = self.count - self.index;
+        (remain, Some(remain))
+    }
+}
+
+impl<'a> ExactSizeIterator for Topics<'a> {}
+
+fn log_decoder_error(_err: DecoderError) -> ReceiptError {
+    warn!("failed: decode receipt since {_err}");
+    ReceiptError::Decode
+}
@@ -1,3 +1,7 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 use core::mem;
 
 use eth2_hashing::hash32_concat;
@@ -27,8 +31,90 @@ pub fn length_hash(length: usize) -> Hash256 {
 }
 
 pub fn verify_merkle_proof(root: Hash256, leaf: Hash256, proof: &[Hash256], index: usize) -> bool {
-    assert_eq!(proof.len(), get_generalized_index_length(index));
-    calculate_merkle_root(leaf, proof, index) == root
+    // The depth of a proof depends on the fork of the block, so a proof for another fork should be
+    // rejected instead of panicking.
+    proof.len() == get_generalized_index_length(index)
+        && calculate_merkle_root(leaf, proof, index) == root
+}
+
+/// Verifies a [multiproof] for several leaves in the same tree.
+///
+/// The leaves are at the generalized indices, and the helpers are the hashes of the nodes at
+/// [`get_helper_indices`] of these indices, which can't be calculated from the leaves.
+///
+/// [multiproof]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/ssz/merkle-proofs.md#merkle-multiproofs
+pub fn verify_multiproof(
+    root: Hash256,
+    leaves: &[Hash256],
+    indices: &[usize],
+    helpers: &[Hash256],
+) -> bool {
+    calculate_multi_merkle_root(leaves, helpers, indices) == Some(root)
+}
+
+/// Returns the generalized indices of the nodes which are required to prove the nodes at the
+/// generalized indices, in decreasing order.
+pub fn get_helper_indices(indices: &[usize]) -> Vec<usize> {
+    let mut branch_indices = BTreeSet::new();
+    let mut path_indices = BTreeSet::new();
+    for index in indices {
+        let mut index = *index;
+        while index > 1 {
+            branch_indices.insert(index ^ 1);
+            path_indices.insert(index);
+            index /= 2;
+        }
+    }
+    let mut helper_indices = branch_indices
+        .difference(&path_indices)
+        .copied()
+        .collect::<Vec<_>>();
+    helper_indices.reverse();
+    helper_indices
+}
+
+fn calculate_multi_merkle_root(
+    leaves: &[Hash256],
+    helpers: &[Hash256],
+    indices: &[usize],
+) -> Option<Hash256> {
+    // The root itself can't be a leaf of a multiproof.
+    if leaves.len() != indices.len() || indices.iter().any(|index| *index < 2) {
+        return None;
+    }
+    let helper_indices = get_helper_indices(indices);
+    if helpers.len() != helper_indices.len() {
+        return None;
+    }
+    let mut objects = BTreeMap::new();
+    for (index, node) in indices
+        .iter()
+        .zip(leaves)
+        .chain(helper_indices.iter().zip(helpers))
+    {
+        if let Some(prev) = objects.insert(*index, *node) {
+            // Duplicated indices are allowed only if their nodes are the same.
+            if prev != *node {
+                return None;
+            }
+        }
+    }
+    // Calculate the parents from the deepest nodes, until the root.
+    while let Some((&index, _)) = objects.iter().next_back() {
+        if index == 1 {
+            break;
+        }
+        let left = objects.remove(&(index & !1))?;
+        let right = objects.remove(&(index | 1))?;
+        let parent = Hash256::from(hash32_concat(left.as_bytes(), right.as_bytes()));
+        if let Some(prev) = objects.insert(index / 2, parent) {
+            // The parent is also provided, it should be the same as the calculated one.
+            if prev != parent {
+                return None;
+            }
+        }
+    }
+    objects.get(&1).copied()
 }
 
 fn calculate_merkle_root(leaf: Hash256, proof: &[Hash256], index: usize) -> Hash256 {
@@ -1,7 +1,7 @@
 use alloc::{borrow::ToOwned as _, vec::Vec};
 use core::cmp;
 
-use rlp::decode_list;
+use rlp::{decode_list, RlpStream};
 use tiny_keccak::{Hasher as _, Keccak};
 
 // Root hash of an empty trie.
@@ -32,6 +32,30 @@ pub fn keccak256(slice: &[u8]) -> [u8; 32] {
     output
 }
 
+/// Encodes an account as the value in the state trie.
+///
+/// The balance is a big-endian 256-bit unsigned integer.
+pub fn encode_account(
+    nonce: u64,
+    balance: &[u8; 32],
+    storage_root: &[u8; 32],
+    code_hash: &[u8; 32],
+) -> Vec<u8> {
+    let mut stream = RlpStream::new_list(4);
+    stream.append(&nonce);
+    stream.append(&trim_leading_zeros(balance));
+    stream.append(&&storage_root[..]);
+    stream.append(&&code_hash[..]);
+    stream.out().to_vec()
+}
+
+/// Encodes a big-endian 256-bit unsigned integer as the value in the storage trie.
+pub fn encode_storage_value(value: &[u8; 32]) -> Vec<u8> {
+    let mut stream = RlpStream::new();
+    stream.append(&trim_leading_zeros(value));
+    stream.out().to_vec()
+}
+
 pub fn verify_proof(proof: &[Vec<u8>], root: &[u8], path: &[u8], value: &[u8]) -> bool {
     let mut expected_hash = root.to_owned();
     let mut path_offset = 0;
@@ -90,6 +114,14 @@ pub fn verify_proof(proof: &[Vec<u8>], root: &[u8], path: &[u8], value: &[u8]) -
     false
 }
 
+fn trim_leading_zeros(value: &[u8]) -> &[u8] {
+    let start = value
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(value.len());
+    &value[start..]
+}
+
 fn paths_match(p1: &[u8], s1: usize, p2: &[u8], s2: usize) -> bool {
     let len1 = p1.len() * 2 - s1;
     let len2 = p2.len() * 2 - s2;